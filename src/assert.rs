@@ -0,0 +1,236 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Sodg};
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+impl<const N: usize> Sodg<N> {
+    /// Compare this graph with another one, vertex by vertex, and panic
+    /// with a human-readable explanation of the differences, if there are any.
+    ///
+    /// This is a testing utility, which is more convenient than `assert_eq!`,
+    /// because its panic message lists vertices that are present only in one
+    /// of the graphs, edges that differ, and data that doesn't match. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.assert_structurally_equal(&g.clone());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the graphs are not equal, it will panic.
+    #[track_caller]
+    pub fn assert_structurally_equal(&self, other: &Self) {
+        let diffs = self.structural_diffs(other);
+        assert!(
+            diffs.is_empty(),
+            "Graphs are not structurally equal, {} difference(s) found:\n{}",
+            diffs.len(),
+            diffs.iter().map(|d| format!("  {d}")).join("\n")
+        );
+    }
+
+    /// Check whether the graph reachable from `root_a` in this graph is
+    /// the same as the graph reachable from `root_b` in the `other` one,
+    /// up to the numbering of vertices: edges must have the same labels
+    /// and vertices must have the same data. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut a : Sodg<16> = Sodg::empty(256);
+    /// a.add(0);
+    /// a.add(1);
+    /// a.bind(0, 1, Label::Alpha(0));
+    /// a.put(1, &Hex::from(42));
+    /// let mut b : Sodg<16> = Sodg::empty(256);
+    /// b.add(7);
+    /// b.add(3);
+    /// b.bind(7, 3, Label::Alpha(0));
+    /// b.put(3, &Hex::from(42));
+    /// assert!(a.same_as(&b, 0, 7));
+    /// b.put(3, &Hex::from(43));
+    /// assert!(!a.same_as(&b, 0, 7));
+    /// ```
+    ///
+    /// If either of the roots is absent, it returns `false`.
+    #[must_use]
+    pub fn same_as(&self, other: &Self, root_a: usize, root_b: usize) -> bool {
+        if !self.alive(root_a) || !other.alive(root_b) {
+            return false;
+        }
+        let mut there: HashMap<usize, usize> = HashMap::from([(root_a, root_b)]);
+        let mut back: HashMap<usize, usize> = HashMap::from([(root_b, root_a)]);
+        let mut todo = vec![(root_a, root_b)];
+        while let Some((a, b)) = todo.pop() {
+            let same_data = match (self.peek(a), other.peek(b)) {
+                (None, None) => true,
+                (Some(x), Some(y)) => x.bytes() == y.bytes(),
+                _ => false,
+            };
+            if !same_data || self.kids(a).count() != other.kids(b).count() {
+                return false;
+            }
+            for (label, ka) in self.kids(a) {
+                let Some(kb) = other.kid(b, *label) else {
+                    return false;
+                };
+                match (there.get(ka), back.get(&kb)) {
+                    (None, None) => {
+                        there.insert(*ka, kb);
+                        back.insert(kb, *ka);
+                        todo.push((*ka, kb));
+                    }
+                    (Some(x), Some(y)) if *x == kb && y == ka => {}
+                    _ => return false,
+                }
+            }
+        }
+        true
+    }
+
+    /// Find all structural differences between two graphs.
+    fn structural_diffs(&self, other: &Self) -> Vec<String> {
+        let ours: HashSet<usize> = self.keys().into_iter().collect();
+        let theirs: HashSet<usize> = other.keys().into_iter().collect();
+        let mut diffs = vec![];
+        for v in ours.difference(&theirs).sorted() {
+            diffs.push(format!("ν{v} is only in the left graph"));
+        }
+        for v in theirs.difference(&ours).sorted() {
+            diffs.push(format!("ν{v} is only in the right graph"));
+        }
+        for v in ours.intersection(&theirs).sorted() {
+            let left = self.vertices.get(*v).unwrap();
+            let right = other.vertices.get(*v).unwrap();
+            for (a, to) in left.edges.iter().sorted() {
+                match right.edges.get(a) {
+                    None => diffs.push(format!("ν{v}.{a} ➞ ν{to} is only in the left graph")),
+                    Some(t) if t != to => diffs.push(format!(
+                        "ν{v}.{a} leads to ν{to} in the left graph, but to ν{t} in the right one"
+                    )),
+                    Some(_) => {}
+                }
+            }
+            for (a, to) in right.edges.iter().sorted() {
+                if !left.edges.contains_key(a) {
+                    diffs.push(format!("ν{v}.{a} ➞ ν{to} is only in the right graph"));
+                }
+            }
+            let ld = self.peek(*v);
+            let rd = other.peek(*v);
+            if ld != rd {
+                diffs.push(format!(
+                    "data of ν{v} is {} in the left graph, but {} in the right one",
+                    ld.map_or_else(|| "absent".to_string(), Hex::print),
+                    rd.map_or_else(|| "absent".to_string(), Hex::print),
+                ));
+            }
+        }
+        diffs
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[test]
+fn passes_on_equal_graphs() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.put(1, &Hex::from(42));
+    g.assert_structurally_equal(&g.clone());
+}
+
+#[test]
+fn explains_differences() {
+    let mut left: Sodg<16> = Sodg::empty(256);
+    left.add(0);
+    left.add(1);
+    left.add(2);
+    left.bind(0, 1, Label::Alpha(0));
+    left.put(1, &Hex::from(42));
+    let mut right: Sodg<16> = Sodg::empty(256);
+    right.add(0);
+    right.add(1);
+    right.add(3);
+    right.bind(0, 3, Label::Alpha(0));
+    right.bind(0, 1, Label::Alpha(1));
+    let err =
+        catch_unwind(AssertUnwindSafe(|| left.assert_structurally_equal(&right))).unwrap_err();
+    let msg = err.downcast_ref::<String>().unwrap();
+    assert!(msg.contains("5 difference(s) found"), "{msg}");
+    assert!(msg.contains("ν2 is only in the left graph"), "{msg}");
+    assert!(msg.contains("ν3 is only in the right graph"), "{msg}");
+    assert!(
+        msg.contains("ν0.α0 leads to ν1 in the left graph, but to ν3 in the right one"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains("ν0.α1 ➞ ν1 is only in the right graph"),
+        "{msg}"
+    );
+    assert!(
+        msg.contains("data of ν1 is 00-00-00-00-00-00-00-2A in the left graph, but absent"),
+        "{msg}"
+    );
+}
+
+#[test]
+fn compares_up_to_renumbering() {
+    let mut a: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        a.add(v);
+    }
+    a.bind(0, 1, Label::Alpha(0));
+    a.bind(0, 2, Label::Alpha(1));
+    a.bind(1, 2, Label::Alpha(0));
+    a.put(2, &Hex::from(42));
+    let mut b: Sodg<16> = Sodg::empty(256);
+    for v in [5, 8, 9] {
+        b.add(v);
+    }
+    b.bind(9, 5, Label::Alpha(0));
+    b.bind(9, 8, Label::Alpha(1));
+    b.bind(5, 8, Label::Alpha(0));
+    b.put(8, &Hex::from(42));
+    assert!(a.same_as(&b, 0, 9));
+    assert!(b.same_as(&a, 9, 0));
+    assert!(!a.same_as(&b, 0, 5));
+    b.add(4);
+    b.bind(5, 4, Label::Alpha(1));
+    assert!(!a.same_as(&b, 0, 9));
+    let mut c = a.clone();
+    c.add(3);
+    c.bind(0, 3, Label::Alpha(1));
+    c.put(3, &Hex::from(42));
+    assert!(!a.same_as(&c, 0, 0));
+    assert!(!a.same_as(&c, 0, 42));
+}
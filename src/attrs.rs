@@ -0,0 +1,119 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use anyhow::{anyhow, Result};
+
+impl<const N: usize> Sodg<N> {
+    /// Attach an attribute to an edge, replacing the previous value
+    /// of the attribute with the same key, if any.
+    ///
+    /// Attributes don't affect the structure of the graph. They stay with
+    /// the edge, when it's renamed, and go away, when its vertex is
+    /// removed or collected as garbage. They are not saved
+    /// by [`Sodg::save`]. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// let a = Label::from_str("foo").unwrap();
+    /// g.bind(0, 1, a);
+    /// g.set_edge_attr(0, a, "color", "red").unwrap();
+    /// assert_eq!(Some("red"), g.get_edge_attr(0, a, "color").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is no such edge, an error will be returned.
+    pub fn set_edge_attr(&mut self, from: usize, a: Label, key: &str, value: &str) -> Result<()> {
+        self.edge_exists(from, a)?;
+        self.attrs
+            .entry((from, a))
+            .or_default()
+            .insert(key.to_string(), value.to_string());
+        Ok(())
+    }
+
+    /// Get an attribute of an edge, if it was set by
+    /// [`Sodg::set_edge_attr`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no such edge, an error will be returned.
+    pub fn get_edge_attr(&self, from: usize, a: Label, key: &str) -> Result<Option<&str>> {
+        self.edge_exists(from, a)?;
+        Ok(self
+            .attrs
+            .get(&(from, a))
+            .and_then(|m| m.get(key))
+            .map(String::as_str))
+    }
+
+    /// Make sure the edge exists.
+    fn edge_exists(&self, from: usize, a: Label) -> Result<()> {
+        if !self.alive(from) || !self.kids(from).any(|(b, _)| *b == a) {
+            return Err(anyhow!("There is no edge ν{from}.{a}"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn sets_and_gets_attributes() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    let a = Label::from_str("foo").unwrap();
+    g.bind(0, 1, a);
+    g.set_edge_attr(0, a, "color", "red").unwrap();
+    g.set_edge_attr(0, a, "color", "blue").unwrap();
+    g.set_edge_attr(0, a, "style", "dashed").unwrap();
+    assert_eq!(Some("blue"), g.get_edge_attr(0, a, "color").unwrap());
+    assert_eq!(None, g.get_edge_attr(0, a, "weight").unwrap());
+    let c = g.clone();
+    assert_eq!(Some("dashed"), c.get_edge_attr(0, a, "style").unwrap());
+    let b = Label::from_str("bar").unwrap();
+    assert!(g.get_edge_attr(0, b, "color").is_err());
+    assert!(g.set_edge_attr(1, a, "color", "red").is_err());
+}
+
+#[test]
+fn keeps_attributes_on_rename() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    let a = Label::from_str("foo").unwrap();
+    let b = Label::from_str("bar").unwrap();
+    g.bind(0, 1, a);
+    g.set_edge_attr(0, a, "color", "red").unwrap();
+    g.rename_edge(0, a, b).unwrap();
+    assert_eq!(Some("red"), g.get_edge_attr(0, b, "color").unwrap());
+    g.map_labels(str::to_uppercase).unwrap();
+    let upper = Label::from_str("BAR").unwrap();
+    assert_eq!(Some("red"), g.get_edge_attr(0, upper, "color").unwrap());
+    g.bind(0, 1, a);
+    assert_eq!(None, g.get_edge_attr(0, a, "color").unwrap());
+}
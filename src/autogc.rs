@@ -0,0 +1,127 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+#[cfg(debug_assertions)]
+use log::trace;
+
+impl<const N: usize> Sodg<N> {
+    /// Turn on automatic reclamation of vertices: each vertex counts
+    /// the edges pointing to it and, when the last of them is removed, by
+    /// [`Sodg::unbind`] or by [`Sodg::bind`] redirecting it, the vertex is
+    /// removed too, together with its own edges, recursively.
+    ///
+    /// Vertices that never had any edges pointing to them, like the root,
+    /// are never removed this way. Cycles are not reclaimed either,
+    /// use [`Sodg::collect`] for them. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256).with_autogc();
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.unbind(0, Label::Alpha(0));
+    /// assert_eq!(1, g.len());
+    /// ```
+    ///
+    /// The counters are not serialized, so a graph restored
+    /// by [`Sodg::load`] doesn't reclaim vertices automatically.
+    #[must_use]
+    pub fn with_autogc(mut self) -> Self {
+        self.refs = Some(self.in_degrees());
+        self
+    }
+
+    /// Count one more edge pointing to the vertex.
+    pub(crate) fn refer(&mut self, v: usize) {
+        if let Some(refs) = &mut self.refs {
+            *refs.entry(v).or_default() += 1;
+        }
+    }
+
+    /// Count one edge less pointing to each of the vertices and remove
+    /// those of them, which have no more edges pointing to them.
+    pub(crate) fn release(&mut self, mut todo: Vec<usize>) {
+        while let Some(v) = todo.pop() {
+            let Some(refs) = &mut self.refs else {
+                return;
+            };
+            let Some(count) = refs.get_mut(&v) else {
+                continue;
+            };
+            *count = count.saturating_sub(1);
+            if *count > 0 || !self.alive(v) {
+                continue;
+            }
+            todo.extend(self.wipe(v));
+            #[cfg(debug_assertions)]
+            trace!("#release: vertex ν{v} reclaimed");
+        }
+    }
+
+    /// Count all edges again, but only if automatic reclamation is on.
+    pub(crate) fn recount(&mut self) {
+        if self.refs.is_some() {
+            self.refs = Some(self.in_degrees());
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn reclaims_vertices_automatically() {
+    let mut g: Sodg<16> = Sodg::empty(256).with_autogc();
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 4, Label::Alpha(1));
+    g.bind(4, 3, Label::Alpha(0));
+    g.bind(0, 5, Label::Alpha(2));
+    g.unbind(0, Label::Alpha(0));
+    assert_eq!(vec![0, 3, 4, 5], {
+        let mut keys = g.keys();
+        keys.sort_unstable();
+        keys
+    });
+    g.bind(0, 4, Label::Alpha(2));
+    assert!(!g.keys().contains(&5));
+    g.unbind(0, Label::Alpha(1));
+    assert_eq!(3, g.keys().len());
+    g.unbind(0, Label::Alpha(2));
+    assert_eq!(vec![0], g.keys());
+}
+
+#[test]
+fn keeps_vertices_without_autogc() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.unbind(0, Label::Alpha(0));
+    assert_eq!(2, g.len());
+}
@@ -0,0 +1,204 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, HashSet, VecDeque};
+
+impl<const N: usize> Sodg<N> {
+    /// Find all vertices reachable from any of the `sources`, together
+    /// with their distances (in edges) from the closest source.
+    ///
+    /// If `max_depth` is provided, vertices farther than that
+    /// are not included. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// let found = g.multi_source_bfs(&[0], Some(1)).unwrap();
+    /// assert_eq!(2, found.len());
+    /// assert_eq!(1, found[&1]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any of the sources is absent, an error will be returned.
+    pub fn multi_source_bfs(
+        &self,
+        sources: &[usize],
+        max_depth: Option<usize>,
+    ) -> Result<HashMap<usize, usize>> {
+        let mut found = HashMap::new();
+        let mut queue = VecDeque::new();
+        for s in sources {
+            if !self.alive(*s) {
+                return Err(anyhow!("Can't find ν{s}"));
+            }
+            if found.insert(*s, 0).is_none() {
+                queue.push_back(*s);
+            }
+        }
+        while let Some(v) = queue.pop_front() {
+            let d = found[&v] + 1;
+            if max_depth.is_some_and(|m| d > m) {
+                continue;
+            }
+            for (_, to) in self.live_kids(v) {
+                if !found.contains_key(to) {
+                    found.insert(*to, d);
+                    queue.push_back(*to);
+                }
+            }
+        }
+        Ok(found)
+    }
+
+    /// Find all vertices that have a path of not more than `max_hops`
+    /// edges to the vertex `v`, excluding `v` itself.
+    ///
+    /// The vertices are returned in ascending order of their IDs.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let g : Sodg<16> = Sodg::chain(4, Label::Alpha(0)).unwrap();
+    /// assert_eq!(vec![1, 2], g.ancestors_within(3, 2).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the vertex `v` is absent, an error will be returned.
+    pub fn ancestors_within(&self, v: usize, max_hops: usize) -> Result<Vec<usize>> {
+        if !self.alive(v) {
+            return Err(anyhow!("Can't find ν{v}"));
+        }
+        let mut parents: HashMap<usize, Vec<usize>> = HashMap::new();
+        for u in self.keys() {
+            for (_, to) in self.live_kids(u) {
+                parents.entry(*to).or_default().push(u);
+            }
+        }
+        let mut seen = HashSet::from([v]);
+        let mut wave = vec![v];
+        for _ in 0..max_hops {
+            let mut next = vec![];
+            for u in wave {
+                for p in parents.get(&u).into_iter().flatten() {
+                    if seen.insert(*p) {
+                        next.push(*p);
+                    }
+                }
+            }
+            wave = next;
+        }
+        seen.remove(&v);
+        let mut found: Vec<usize> = seen.into_iter().collect();
+        found.sort_unstable();
+        Ok(found)
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+fn two_chains() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..8 {
+        g.add(v);
+    }
+    for v in [0, 1, 2, 4, 5, 6] {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    g
+}
+
+#[cfg(test)]
+fn collected() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    g
+}
+
+#[test]
+fn reaches_from_all_sources() {
+    let g = two_chains();
+    let found = g.multi_source_bfs(&[0, 4], None).unwrap();
+    assert_eq!(8, found.len());
+    assert_eq!(3, found[&3]);
+    assert_eq!(3, found[&7]);
+}
+
+#[test]
+fn takes_closest_source() {
+    let mut g = two_chains();
+    g.bind(0, 6, Label::Alpha(1));
+    let found = g.multi_source_bfs(&[0, 4], Some(2)).unwrap();
+    assert_eq!(1, found[&6]);
+    assert_eq!(2, found[&7]);
+    assert!(!found.contains_key(&3));
+}
+
+#[test]
+fn stays_at_sources() {
+    let g = two_chains();
+    let found = g.multi_source_bfs(&[1, 5], Some(0)).unwrap();
+    assert_eq!(HashMap::from([(1, 0), (5, 0)]), found);
+    assert!(g.multi_source_bfs(&[42], None).is_err());
+}
+
+#[test]
+fn finds_ancestors_within_hops() {
+    let mut g = two_chains();
+    g.bind(5, 3, Label::Alpha(1));
+    assert_eq!(vec![2, 5], g.ancestors_within(3, 1).unwrap());
+    assert_eq!(vec![1, 2, 4, 5], g.ancestors_within(3, 2).unwrap());
+    assert_eq!(vec![0, 1, 2, 4, 5], g.ancestors_within(3, 100).unwrap());
+    assert!(g.ancestors_within(3, 0).unwrap().is_empty());
+    let long: Sodg<16> = Sodg::chain(16, Label::Alpha(0)).unwrap();
+    assert_eq!(vec![12, 13, 14], long.ancestors_within(15, 3).unwrap());
+}
+
+#[test]
+fn skips_collected_vertices() {
+    let g = collected();
+    let found = g.multi_source_bfs(&[0], None).unwrap();
+    assert_eq!(HashMap::from([(0, 0), (1, 1)]), found);
+}
+
+#[test]
+fn finds_no_ancestors_through_collected_vertices() {
+    let g = collected();
+    assert_eq!(vec![0], g.ancestors_within(1, 100).unwrap());
+    assert!(g.ancestors_within(3, 1).is_err());
+}
@@ -0,0 +1,161 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+impl<const N: usize> Sodg<N> {
+    /// Calculate betweenness centrality of all vertices, using the
+    /// algorithm of Brandes, in `O(VE)` time.
+    ///
+    /// The centrality of a vertex is the sum, over all pairs of other
+    /// vertices, of the fractions of shortest paths between them that go
+    /// through this vertex. Edges are directed and the result is not
+    /// normalized. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// let c = g.betweenness_centrality();
+    /// assert_eq!(1.0, c[&1]);
+    /// assert_eq!(0.0, c[&0]);
+    /// ```
+    #[must_use]
+    pub fn betweenness_centrality(&self) -> HashMap<usize, f64> {
+        let keys = self.keys();
+        let succ: HashMap<usize, BTreeSet<usize>> = keys
+            .iter()
+            .map(|v| {
+                (
+                    *v,
+                    self.kids(*v)
+                        .map(|(_, to)| *to)
+                        .filter(|to| self.alive(*to))
+                        .collect(),
+                )
+            })
+            .collect();
+        let mut centrality: HashMap<usize, f64> = keys.iter().map(|v| (*v, 0.0)).collect();
+        for s in &keys {
+            let mut stack = vec![];
+            let mut preds: HashMap<usize, Vec<usize>> = HashMap::new();
+            let mut sigma: HashMap<usize, f64> = HashMap::from([(*s, 1.0)]);
+            let mut dist: HashMap<usize, usize> = HashMap::from([(*s, 0)]);
+            let mut queue = VecDeque::from([*s]);
+            while let Some(v) = queue.pop_front() {
+                stack.push(v);
+                for w in &succ[&v] {
+                    if !dist.contains_key(w) {
+                        dist.insert(*w, dist[&v] + 1);
+                        queue.push_back(*w);
+                    }
+                    if dist[w] == dist[&v] + 1 {
+                        *sigma.entry(*w).or_default() += sigma[&v];
+                        preds.entry(*w).or_default().push(v);
+                    }
+                }
+            }
+            let mut delta: HashMap<usize, f64> = HashMap::new();
+            while let Some(w) = stack.pop() {
+                let dw = delta.get(&w).copied().unwrap_or(0.0);
+                for v in preds.get(&w).into_iter().flatten() {
+                    *delta.entry(*v).or_default() += sigma[v] / sigma[&w] * (1.0 + dw);
+                }
+                if w != *s {
+                    *centrality.entry(w).or_default() += dw;
+                }
+            }
+        }
+        centrality
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn finds_middle_of_a_chain() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    for v in 0..4 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    let c = g.betweenness_centrality();
+    assert_eq!(
+        vec![0.0, 3.0, 4.0, 3.0, 0.0],
+        (0..5).map(|v| c[&v]).collect::<Vec<f64>>()
+    );
+}
+
+#[test]
+fn counts_paths_through_star_center() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(1, 0, Label::Alpha(0));
+    g.bind(2, 0, Label::Alpha(0));
+    for v in 3..6 {
+        g.bind(0, v, Label::Alpha(v));
+    }
+    let c = g.betweenness_centrality();
+    assert!((c[&0] - 6.0).abs() < f64::EPSILON);
+    for v in 1..6 {
+        assert!(c[&v].abs() < f64::EPSILON);
+    }
+}
+
+#[test]
+fn splits_between_equal_paths() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    let c = g.betweenness_centrality();
+    assert!((c[&1] - 0.5).abs() < f64::EPSILON);
+    assert!((c[&2] - 0.5).abs() < f64::EPSILON);
+    assert!((c.values().sum::<f64>() - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn skips_collected_targets() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let c = g.betweenness_centrality();
+    assert_eq!(g.len(), c.len());
+}
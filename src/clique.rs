@@ -0,0 +1,178 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::{BTreeSet, HashMap};
+
+impl<const N: usize> Sodg<N> {
+    /// Find the largest clique (a subset of vertices, where each pair
+    /// is connected), treating the graph as undirected.
+    ///
+    /// The search is Bron-Kerbosch with pivoting, which also prunes
+    /// the branches that can't give a clique bigger than the one already
+    /// found. The problem is NP-hard, so this is practical only for graphs
+    /// of about a thousand vertices. Vertices are returned sorted. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(2, 0, Label::Alpha(0));
+    /// assert_eq!(vec![0, 1, 2], g.approx_max_clique());
+    /// ```
+    #[must_use]
+    pub fn approx_max_clique(&self) -> Vec<usize> {
+        let adj = self.undirected();
+        let mut best = vec![];
+        Self::bron_kerbosch(
+            &adj,
+            &mut vec![],
+            adj.keys().copied().collect(),
+            BTreeSet::new(),
+            &mut best,
+        );
+        best.sort_unstable();
+        best
+    }
+
+    /// Make an undirected adjacency map of all vertices, ignoring loops.
+    pub(crate) fn undirected(&self) -> HashMap<usize, BTreeSet<usize>> {
+        let mut adj: HashMap<usize, BTreeSet<usize>> = self
+            .keys()
+            .into_iter()
+            .map(|v| (v, BTreeSet::new()))
+            .collect();
+        for v in self.keys() {
+            for (_, to) in self.live_kids(v) {
+                if *to != v {
+                    adj.get_mut(&v).unwrap().insert(*to);
+                    adj.entry(*to).or_default().insert(v);
+                }
+            }
+        }
+        adj
+    }
+
+    /// One step of Bron-Kerbosch, with pivoting and pruning.
+    fn bron_kerbosch(
+        adj: &HashMap<usize, BTreeSet<usize>>,
+        clique: &mut Vec<usize>,
+        mut candidates: BTreeSet<usize>,
+        mut excluded: BTreeSet<usize>,
+        best: &mut Vec<usize>,
+    ) {
+        if candidates.is_empty() && excluded.is_empty() {
+            if clique.len() > best.len() {
+                best.clone_from(clique);
+            }
+            return;
+        }
+        if clique.len() + candidates.len() <= best.len() {
+            return;
+        }
+        let pivot = candidates
+            .union(&excluded)
+            .max_by_key(|u| adj[u].intersection(&candidates).count())
+            .copied()
+            .unwrap();
+        let todo: Vec<usize> = candidates.difference(&adj[&pivot]).copied().collect();
+        for v in todo {
+            clique.push(v);
+            Self::bron_kerbosch(
+                adj,
+                clique,
+                candidates.intersection(&adj[&v]).copied().collect(),
+                excluded.intersection(&adj[&v]).copied().collect(),
+                best,
+            );
+            clique.pop();
+            candidates.remove(&v);
+            excluded.insert(v);
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+use crate::ctors::complete;
+
+#[cfg(test)]
+fn is_clique(g: &Sodg<16>, vs: &[usize]) -> bool {
+    vs.iter().all(|a| {
+        vs.iter().all(|b| {
+            a == b || g.kids(*a).any(|(_, to)| to == b) || g.kids(*b).any(|(_, to)| to == a)
+        })
+    })
+}
+
+#[test]
+fn finds_complete_graphs() {
+    assert_eq!(vec![0, 1, 2, 3, 4], complete(5).approx_max_clique());
+    assert_eq!(vec![0, 1, 2], complete(3).approx_max_clique());
+}
+
+#[test]
+fn finds_an_edge_in_a_path() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    let c = g.approx_max_clique();
+    assert_eq!(2, c.len());
+    assert!(is_clique(&g, &c));
+}
+
+#[test]
+fn finds_clique_inside_noise() {
+    let mut g = complete(4);
+    for v in 4..8 {
+        g.add(v);
+        g.bind(v - 1, v, Label::Alpha(10));
+    }
+    g.bind(7, 5, Label::Alpha(11));
+    let c = g.approx_max_clique();
+    assert_eq!(vec![0, 1, 2, 3], c);
+    assert!(is_clique(&g, &c));
+}
+
+#[test]
+fn skips_collected_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    assert_eq!(vec![0, 1], g.approx_max_clique());
+    assert!(g.is_connected());
+}
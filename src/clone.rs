@@ -19,14 +19,25 @@
 // SOFTWARE.
 
 use crate::Sodg;
+use std::collections::HashMap;
 
 impl<const N: usize> Clone for Sodg<N> {
     /// Make a clone of the graph.
+    ///
+    /// Callbacks registered by [`Sodg::watch_vertex`] are not cloned.
     fn clone(&self) -> Self {
         Self {
             vertices: self.vertices.clone(),
             branches: self.branches.clone(),
             stores: self.stores.clone(),
+            names: self.names.clone(),
+            named: self.named.clone(),
+            attrs: self.attrs.clone(),
+            watchers: HashMap::new(),
+            tracing: self.tracing,
+            traces: self.traces.clone(),
+            index: self.index.clone(),
+            refs: self.refs.clone(),
             next_v: self.next_v,
         }
     }
@@ -46,6 +57,7 @@ fn makes_a_clone() {
 }
 
 #[test]
+#[allow(clippy::redundant_clone)]
 fn makes_an_empty_clone() {
     let g: Sodg<16> = Sodg::empty(256);
     let c = g.clone();
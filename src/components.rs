@@ -0,0 +1,266 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::{HashMap, HashSet};
+
+impl<const N: usize> Sodg<N> {
+    /// Split the graph into weakly connected components, making
+    /// a separate graph for each of them, with the same IDs of vertices.
+    ///
+    /// Components are ordered by the smallest IDs of their vertices.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let parts = g.split_by_component();
+    /// assert_eq!(2, parts.len());
+    /// assert_eq!(2, parts[0].len());
+    /// assert_eq!(1, parts[1].len());
+    /// ```
+    #[must_use]
+    pub fn split_by_component(&self) -> Vec<Self> {
+        self.components().iter().map(|c| self.induced(c)).collect()
+    }
+
+    /// Split the graph into two: the vertices that satisfy the predicate
+    /// and all others, keeping the same IDs of vertices.
+    ///
+    /// Edges between the two parts are lost. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 2, Label::Alpha(0));
+    /// g.bind(0, 1, Label::Alpha(1));
+    /// let (even, odd) = g.partition_by(|v| v % 2 == 0);
+    /// assert_eq!(2, even.len());
+    /// assert_eq!(1, odd.len());
+    /// assert_eq!(1, even.kids(0).count());
+    /// ```
+    #[must_use]
+    pub fn partition_by<F: Fn(usize) -> bool>(&self, pred: F) -> (Self, Self) {
+        let (yes, no): (HashSet<usize>, HashSet<usize>) =
+            self.keys().into_iter().partition(|v| pred(*v));
+        (self.induced(&yes), self.induced(&no))
+    }
+
+    /// Check whether the graph is weakly connected, which means that
+    /// there is a path between any two vertices, if directions of edges
+    /// are ignored. An empty graph is connected.
+    #[must_use]
+    pub fn is_connected(&self) -> bool {
+        self.components().len() <= 1
+    }
+
+    /// Find all strongly connected components, where each vertex is
+    /// reachable from any other one by directed edges, using
+    /// Tarjan's algorithm.
+    ///
+    /// Vertices in each component are sorted by their IDs, while components
+    /// are ordered by the smallest IDs of their vertices. A vertex, which
+    /// is not on any cycle, is a component of its own. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(2, 1, Label::Alpha(0));
+    /// assert_eq!(vec![vec![0], vec![1, 2]], g.sccs());
+    /// ```
+    #[must_use]
+    pub fn sccs(&self) -> Vec<Vec<usize>> {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut index: HashMap<usize, usize> = HashMap::new();
+        let mut low: HashMap<usize, usize> = HashMap::new();
+        let mut stack: Vec<usize> = vec![];
+        let mut on_stack: HashSet<usize> = HashSet::new();
+        let mut all = vec![];
+        for root in keys {
+            if index.contains_key(&root) {
+                continue;
+            }
+            let mut frames: Vec<(usize, Vec<usize>)> = vec![];
+            let mut next = Some(root);
+            loop {
+                if let Some(v) = next.take() {
+                    index.insert(v, index.len());
+                    low.insert(v, index[&v]);
+                    stack.push(v);
+                    on_stack.insert(v);
+                    let mut kids: Vec<usize> = self.live_kids(v).map(|(_, to)| *to).collect();
+                    kids.reverse();
+                    frames.push((v, kids));
+                }
+                let Some((v, kids)) = frames.last_mut() else {
+                    break;
+                };
+                let v = *v;
+                if let Some(w) = kids.pop() {
+                    if !index.contains_key(&w) {
+                        next = Some(w);
+                    } else if on_stack.contains(&w) {
+                        low.insert(v, low[&v].min(index[&w]));
+                    }
+                    continue;
+                }
+                frames.pop();
+                if let Some((u, _)) = frames.last() {
+                    low.insert(*u, low[u].min(low[&v]));
+                }
+                if low[&v] == index[&v] {
+                    let mut component = vec![];
+                    while let Some(w) = stack.pop() {
+                        on_stack.remove(&w);
+                        component.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    component.sort_unstable();
+                    all.push(component);
+                }
+            }
+        }
+        all.sort_unstable_by_key(|c| c[0]);
+        all
+    }
+
+    /// Find all weakly connected components, ordered by the smallest
+    /// IDs of their vertices.
+    fn components(&self) -> Vec<HashSet<usize>> {
+        let adj = self.undirected();
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut seen = HashSet::new();
+        let mut all = vec![];
+        for v in keys {
+            if !seen.insert(v) {
+                continue;
+            }
+            let mut component = HashSet::from([v]);
+            let mut todo = vec![v];
+            while let Some(u) = todo.pop() {
+                for w in &adj[&u] {
+                    if seen.insert(*w) {
+                        component.insert(*w);
+                        todo.push(*w);
+                    }
+                }
+            }
+            all.push(component);
+        }
+        all
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn keeps_connected_graph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 1, Label::Alpha(0));
+    assert!(g.is_connected());
+    let parts = g.split_by_component();
+    assert_eq!(1, parts.len());
+    g.assert_structurally_equal(&parts[0]);
+}
+
+#[test]
+fn splits_into_components() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(4, 3, Label::Alpha(0));
+    g.put(3, &Hex::from(42));
+    assert!(!g.is_connected());
+    let parts = g.split_by_component();
+    assert_eq!(3, parts.len());
+    let mut all: Vec<usize> = parts.iter().flat_map(Sodg::keys).collect();
+    all.sort_unstable();
+    assert_eq!((0..6).collect::<Vec<usize>>(), all);
+    assert!(parts.iter().all(Sodg::is_connected));
+    assert_eq!(Some(3), parts[1].kid(4, Label::Alpha(0)));
+    assert_eq!(42, parts[1].clone().data(3).unwrap().to_i64().unwrap());
+}
+
+#[test]
+fn partitions_by_parity() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 2, Label::Alpha(0));
+    g.bind(2, 4, Label::Alpha(0));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(0, 1, Label::Alpha(1));
+    g.bind(5, 4, Label::Alpha(0));
+    let (even, odd) = g.partition_by(|v| v % 2 == 0);
+    let mut keys = even.keys();
+    keys.sort_unstable();
+    assert_eq!(vec![0, 2, 4], keys);
+    let mut keys = odd.keys();
+    keys.sort_unstable();
+    assert_eq!(vec![1, 3, 5], keys);
+    assert_eq!(Some(2), even.kid(0, Label::Alpha(0)));
+    assert_eq!(Some(4), even.kid(2, Label::Alpha(0)));
+    assert_eq!(None, even.kid(0, Label::Alpha(1)));
+    assert_eq!(Some(3), odd.kid(1, Label::Alpha(0)));
+    assert_eq!(None, odd.kid(5, Label::Alpha(0)));
+}
+
+#[test]
+fn finds_strongly_connected_components() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..7 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(2, 0, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(1));
+    g.bind(3, 4, Label::Alpha(0));
+    g.bind(4, 5, Label::Alpha(0));
+    g.bind(5, 3, Label::Alpha(0));
+    g.bind(6, 5, Label::Alpha(0));
+    assert_eq!(vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]], g.sccs());
+    assert!(Sodg::<16>::empty(16).sccs().is_empty());
+}
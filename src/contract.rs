@@ -0,0 +1,147 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Result};
+
+impl<const N: usize> Sodg<N> {
+    /// Replace a chain of vertices, which starts at `start`, with
+    /// a single edge.
+    ///
+    /// The function follows the only outgoing edge of `start`, while each
+    /// next vertex has exactly one incoming and one outgoing edge. Such
+    /// vertices are deleted, while the edge of `start` is redirected to the
+    /// first vertex that doesn't look like that. The label of the edge
+    /// stays the same. If `start` doesn't have exactly one outgoing edge,
+    /// nothing happens. The function returns the number of vertices deleted.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// assert_eq!(1, g.contract_path(0).unwrap());
+    /// assert_eq!(Some(2), g.kid(0, Label::Alpha(0)));
+    /// ```
+    ///
+    /// Vertices with data are never deleted silently: if any of them
+    /// is in the chain, an error is returned and the graph stays intact.
+    ///
+    /// # Errors
+    ///
+    /// If `start` is absent or there is data in the chain,
+    /// an error will be returned.
+    pub fn contract_path(&mut self, start: usize) -> Result<usize> {
+        if !self.alive(start) {
+            return Err(anyhow!("Can't find ν{start}"));
+        }
+        let edges: Vec<_> = self.kids(start).map(|(a, to)| (*a, *to)).collect();
+        let [(a, first)] = edges[..] else {
+            return Ok(0);
+        };
+        let degrees = self.in_degrees();
+        let mut chain = vec![];
+        let mut cur = first;
+        while cur != start && degrees[&cur] == 1 {
+            let mut kids = self.kids(cur);
+            let (Some((_, next)), None) = (kids.next(), kids.next()) else {
+                break;
+            };
+            if *next == start {
+                break;
+            }
+            if self.peek(cur).is_some() {
+                return Err(anyhow!(
+                    "Can't contract ν{cur}, because it has data, in a chain from ν{start}"
+                ));
+            }
+            chain.push(cur);
+            cur = *next;
+        }
+        for v in &chain {
+            self.erase(*v);
+        }
+        if !chain.is_empty() {
+            self.bind(start, cur, a);
+        }
+        Ok(chain.len())
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn contracts_linear_chain() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    for v in 0..4 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    assert_eq!(3, g.contract_path(0).unwrap());
+    assert_eq!(2, g.len());
+    assert_eq!(Some(4), g.kid(0, Label::Alpha(0)));
+}
+
+#[test]
+fn ignores_branching_vertex() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    assert_eq!(0, g.contract_path(0).unwrap());
+    assert_eq!(4, g.len());
+}
+
+#[test]
+fn stops_at_loop() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    for v in 0..3 {
+        g.bind(v, (v + 1) % 3, Label::Alpha(0));
+    }
+    assert_eq!(1, g.contract_path(0).unwrap());
+    assert_eq!(Some(2), g.kid(0, Label::Alpha(0)));
+    assert_eq!(Some(0), g.kid(2, Label::Alpha(0)));
+}
+
+#[test]
+fn refuses_to_drop_data() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.put(1, &Hex::from(42));
+    assert!(g.contract_path(0).is_err());
+    assert_eq!(3, g.len());
+}
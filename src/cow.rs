@@ -0,0 +1,101 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{CowSodg, Sodg};
+use std::rc::Rc;
+
+impl<const N: usize> Sodg<N> {
+    /// Turn the graph into a [`CowSodg`], which can be cloned in
+    /// constant time. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// let cow = g.into_cow();
+    /// let mut copy = cow.clone();
+    /// copy.graph_mut().add(1);
+    /// assert_eq!(1, cow.graph().len());
+    /// assert_eq!(2, copy.graph().len());
+    /// ```
+    #[must_use]
+    pub fn into_cow(self) -> CowSodg<N> {
+        CowSodg { g: Rc::new(self) }
+    }
+}
+
+impl<const N: usize> Clone for CowSodg<N> {
+    /// Make a clone, which shares the graph with this one.
+    fn clone(&self) -> Self {
+        Self {
+            g: Rc::clone(&self.g),
+        }
+    }
+}
+
+impl<const N: usize> CowSodg<N> {
+    /// Get the graph, to read it.
+    #[must_use]
+    pub fn graph(&self) -> &Sodg<N> {
+        &self.g
+    }
+
+    /// Get the graph, to modify it.
+    ///
+    /// If the graph is shared with other clones, it is copied
+    /// first, the same way [`Sodg::clone`] does it.
+    pub fn graph_mut(&mut self) -> &mut Sodg<N> {
+        Rc::make_mut(&mut self.g)
+    }
+
+    /// Is the graph shared with other clones?
+    #[must_use]
+    pub fn is_shared(&self) -> bool {
+        Rc::strong_count(&self.g) > 1
+    }
+
+    /// Take the graph out, copying it if it's shared.
+    #[must_use]
+    pub fn into_inner(self) -> Sodg<N> {
+        Rc::try_unwrap(self.g).unwrap_or_else(|g| (*g).clone())
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn copies_on_write() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    let cow = g.into_cow();
+    let mut copy = cow.clone();
+    assert!(cow.is_shared());
+    assert!(std::ptr::eq(cow.graph(), copy.graph()));
+    copy.graph_mut().add(2);
+    copy.graph_mut().bind(1, 2, Label::Alpha(0));
+    assert!(!cow.is_shared());
+    assert_eq!(2, cow.graph().len());
+    assert_eq!(None, cow.graph().kid(1, Label::Alpha(0)));
+    assert_eq!(Some(2), copy.graph().kid(1, Label::Alpha(0)));
+    assert_eq!(3, copy.into_inner().len());
+}
@@ -18,8 +18,12 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{Hex, Persistence, Sodg, Vertex, MAX_BRANCHES};
+use crate::{Hex, Label, Persistence, Sodg, Vertex, MAX_BRANCHES, MAX_BRANCH_SIZE};
+use anyhow::{anyhow, Result};
 use emap::Map;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::str::FromStr;
 
 impl<const N: usize> Sodg<N> {
     /// Make an empty [`Sodg`], with no vertices and no edges.
@@ -41,6 +45,14 @@ impl<const N: usize> Sodg<N> {
             ),
             stores: Map::with_capacity_some(MAX_BRANCHES, 0),
             branches: Map::with_capacity_some(MAX_BRANCHES, microstack::Stack::new()),
+            names: HashMap::new(),
+            named: HashMap::new(),
+            attrs: HashMap::new(),
+            watchers: HashMap::new(),
+            tracing: false,
+            traces: RefCell::new(HashMap::new()),
+            index: None,
+            refs: None,
             next_v: 0,
         };
         g.branches
@@ -49,6 +61,130 @@ impl<const N: usize> Sodg<N> {
             .insert(1, microstack::Stack::from_vec([0].to_vec()));
         g
     }
+
+    /// Make a chain of `n` vertices, from `ν0` to `ν(n-1)`, where each
+    /// vertex has an edge labeled `a` to the next one. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let next = Label::from_str("next").unwrap();
+    /// let g : Sodg<16> = Sodg::chain(3, next).unwrap();
+    /// assert_eq!(Some(2), g.kid(1, next));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the chain is too long to be kept in one branch,
+    /// an error will be returned.
+    pub fn chain(n: usize, a: Label) -> Result<Self> {
+        if n > MAX_BRANCH_SIZE {
+            return Err(anyhow!(
+                "The chain of {n} vertices is too long, {MAX_BRANCH_SIZE} is the maximum"
+            ));
+        }
+        let mut g = Self::empty(n.max(1));
+        for v in 0..n {
+            g.add(v);
+            if v > 0 {
+                g.bind(v - 1, v, a);
+            }
+        }
+        Ok(g)
+    }
+
+    /// Make a star: the vertex `ν0` in the center and `n` vertices around,
+    /// from `ν1` to `νn`, where the edge to the vertex `νi`
+    /// is labeled as `{prefix}_{i}`. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let g : Sodg<16> = Sodg::star(3, "f").unwrap();
+    /// assert_eq!(Some(2), g.kid(0, Label::from_str("f_2").unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there are too many vertices or labels can't be
+    /// parsed, an error will be returned.
+    pub fn star(n: usize, prefix: &str) -> Result<Self> {
+        if n > N || n + 1 > MAX_BRANCH_SIZE {
+            return Err(anyhow!("The star with {n} rays is too big"));
+        }
+        let mut g = Self::empty(n + 1);
+        g.add(0);
+        for v in 1..=n {
+            let a = Label::from_str(&format!("{prefix}_{v}"))?;
+            g.add(v);
+            g.bind(0, v, a);
+        }
+        Ok(g)
+    }
+
+    /// Make a complete directed graph of `n` vertices, from `ν0`
+    /// to `ν(n-1)`, where each vertex `νi` has an edge to each other
+    /// vertex `νj`, labeled as `label(i, j)`. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let g : Sodg<16> = Sodg::complete(3, |i, j| format!("{i}_{j}")).unwrap();
+    /// assert_eq!(Some(0), g.kid(2, Label::from_str("2_0").unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there are too many vertices or labels can't be
+    /// parsed, an error will be returned.
+    pub fn complete<F: Fn(usize, usize) -> String>(n: usize, label: F) -> Result<Self> {
+        if n > N + 1 || n > MAX_BRANCH_SIZE {
+            return Err(anyhow!("The complete graph of {n} vertices is too big"));
+        }
+        let mut g = Self::empty(n.max(1));
+        for v in 0..n {
+            g.add(v);
+        }
+        for i in 0..n {
+            for j in (0..n).filter(|j| *j != i) {
+                g.bind(i, j, Label::from_str(&label(i, j))?);
+            }
+        }
+        Ok(g)
+    }
+
+    /// Make a chain, the same way as [`Sodg::chain`] does, with as many
+    /// vertices as there are items in `data`, putting `data[i]`
+    /// into the vertex `νi`.
+    ///
+    /// # Errors
+    ///
+    /// If the chain is too long to be kept in one branch,
+    /// an error will be returned.
+    pub fn chain_with_data(data: &[Vec<u8>], a: Label) -> Result<Self> {
+        let mut g = Self::chain(data.len(), a)?;
+        for (v, d) in data.iter().enumerate() {
+            g.put(v, &Hex::from_slice(d));
+        }
+        Ok(g)
+    }
+}
+
+/// Make a complete graph of `n` vertices, where each vertex has an edge
+/// `α{u}` to every other vertex `νu`, with room for 256 vertices,
+/// to be used in tests that grow it further.
+#[cfg(test)]
+pub fn complete(n: usize) -> Sodg<16> {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..n {
+        g.add(v);
+    }
+    for v in 0..n {
+        for u in (0..n).filter(|u| *u != v) {
+            g.bind(v, u, Label::Alpha(u));
+        }
+    }
+    g
 }
 
 #[test]
@@ -57,3 +193,52 @@ fn makes_an_empty_sodg() {
     g.add(0);
     assert_eq!(1, g.len());
 }
+
+#[test]
+fn makes_a_chain() {
+    let next = Label::from_str("next").unwrap();
+    let g: Sodg<16> = Sodg::chain(5, next).unwrap();
+    assert_eq!(5, g.len());
+    assert_eq!(
+        4,
+        g.keys().iter().map(|v| g.kids(*v).count()).sum::<usize>()
+    );
+    for v in 0..4 {
+        assert_eq!(Some(v + 1), g.kid(v, next));
+    }
+    assert_eq!(None, g.kid(4, next));
+    assert!(Sodg::<16>::chain(0, next).unwrap().is_empty());
+    assert!(Sodg::<16>::chain(100, next).is_err());
+}
+
+#[test]
+fn makes_a_chain_with_data() {
+    let mut g: Sodg<16> =
+        Sodg::chain_with_data(&[b"a".to_vec(), vec![], b"ccc".to_vec()], Label::Alpha(0)).unwrap();
+    assert_eq!(3, g.len());
+    assert_eq!(b"a".to_vec(), g.data(0).unwrap().to_vec());
+    assert_eq!(b"ccc".to_vec(), g.data(2).unwrap().to_vec());
+    assert_eq!(Some(2), g.kid(1, Label::Alpha(0)));
+}
+
+#[test]
+fn makes_a_star() {
+    let g: Sodg<16> = Sodg::star(3, "field").unwrap();
+    assert_eq!(4, g.len());
+    let labels: Vec<String> = g.kids(0).map(|(a, _)| a.to_string()).collect();
+    assert_eq!(vec!["field_1", "field_2", "field_3"], labels);
+    assert_eq!(Some(2), g.kid(0, Label::from_str("field_2").unwrap()));
+    assert!(Sodg::<16>::star(20, "x").is_err());
+}
+
+#[test]
+fn makes_a_complete_graph() {
+    let g: Sodg<16> = Sodg::complete(3, |i, j| format!("{i}_{j}")).unwrap();
+    assert_eq!(3, g.len());
+    assert_eq!(
+        6,
+        g.keys().iter().map(|v| g.kids(*v).count()).sum::<usize>()
+    );
+    assert_eq!(Some(1), g.kid(0, Label::from_str("0_1").unwrap()));
+    assert!(Sodg::<16>::complete(3, |_, _| "much_too_long".to_string()).is_err());
+}
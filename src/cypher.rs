@@ -0,0 +1,125 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use regex::Regex;
+use std::sync::LazyLock;
+
+static IDENTIFIER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new("^[A-Za-z_][A-Za-z0-9_]*$").unwrap());
+
+impl<const N: usize> Sodg<N> {
+    /// Print the graph as a Cypher query, which creates the same graph
+    /// in [Neo4j](https://neo4j.com), if pasted into Neo4j Browser.
+    ///
+    /// Each vertex becomes a node with the `Vertex` label and the `id`
+    /// property. Data, if present, go into the `data` property, as a
+    /// hexadecimal string. Each edge becomes a relationship, whose type is
+    /// the label of the edge, quoted with backticks if necessary.
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// assert_eq!(
+    ///     "CREATE (v0:Vertex {id: 0})\n\
+    ///     CREATE (v1:Vertex {id: 1})\n\
+    ///     CREATE (v0)-[:foo]->(v1);\n",
+    ///     g.to_cypher()
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_cypher(&self) -> String {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut lines = vec![];
+        for v in &keys {
+            match self.peek(*v) {
+                Some(d) => lines.push(format!(
+                    "CREATE (v{v}:Vertex {{id: {v}, data: '{}'}})",
+                    d.print()
+                )),
+                None => lines.push(format!("CREATE (v{v}:Vertex {{id: {v}}})")),
+            }
+        }
+        for v in &keys {
+            let mut kids: Vec<_> = self.kids(*v).collect();
+            kids.sort();
+            for (a, to) in kids {
+                lines.push(format!(
+                    "CREATE (v{v})-[:{}]->(v{to})",
+                    Self::cypher_type(*a)
+                ));
+            }
+        }
+        if lines.is_empty() {
+            return String::new();
+        }
+        format!("{};\n", lines.join("\n"))
+    }
+
+    /// Turn a label into a relationship type, quoting it if it's
+    /// not a valid identifier.
+    fn cypher_type(a: Label) -> String {
+        let s = a.to_string();
+        if IDENTIFIER.is_match(&s) {
+            s
+        } else {
+            format!("`{}`", s.replace('`', "``"))
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::Hex;
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn prints_known_graph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(0, 2, Label::Alpha(0));
+    g.bind(1, 2, Label::from_str("a-b").unwrap());
+    g.put(2, &Hex::from_str_bytes("hi"));
+    assert_eq!(
+        "CREATE (v0:Vertex {id: 0})\n\
+        CREATE (v1:Vertex {id: 1})\n\
+        CREATE (v2:Vertex {id: 2, data: '68-69'})\n\
+        CREATE (v0)-[:`α0`]->(v2)\n\
+        CREATE (v0)-[:foo]->(v1)\n\
+        CREATE (v1)-[:`a-b`]->(v2);\n",
+        g.to_cypher()
+    );
+}
+
+#[test]
+fn prints_empty_graph() {
+    let g: Sodg<16> = Sodg::empty(256);
+    assert!(g.to_cypher().is_empty());
+}
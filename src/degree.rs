@@ -0,0 +1,85 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Count the edges that arrive to the vertex `v`.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 1, Label::Alpha(1));
+    /// assert_eq!(2, g.in_degree(1));
+    /// assert_eq!(0, g.in_degree(0));
+    /// ```
+    #[must_use]
+    pub fn in_degree(&self, v: usize) -> usize {
+        self.keys()
+            .into_iter()
+            .map(|u| self.kids(u).filter(|(_, to)| **to == v).count())
+            .sum()
+    }
+
+    /// Count the edges that depart from the vertex `v`.
+    ///
+    /// # Panics
+    ///
+    /// If vertex `v` is absent, it will panic.
+    #[must_use]
+    pub fn out_degree(&self, v: usize) -> usize {
+        self.vertices.get(v).unwrap().edges.len()
+    }
+
+    /// Count incoming edges of all vertices at once.
+    pub(crate) fn in_degrees(&self) -> HashMap<usize, usize> {
+        let mut degrees: HashMap<usize, usize> = self.keys().into_iter().map(|v| (v, 0)).collect();
+        for v in self.keys() {
+            for (_, to) in self.live_kids(v) {
+                *degrees.entry(*to).or_default() += 1;
+            }
+        }
+        degrees
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn counts_degrees() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    assert_eq!(2, g.in_degree(1));
+    assert_eq!(1, g.out_degree(1));
+    assert_eq!(2, *g.in_degrees().get(&1).unwrap());
+    assert_eq!(0, *g.in_degrees().get(&0).unwrap());
+}
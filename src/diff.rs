@@ -0,0 +1,244 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Patch, PatchOp, Sodg};
+use anyhow::{anyhow, Context, Result};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+impl<const N: usize> Sodg<N> {
+    /// Find the difference between this graph and the `other` one,
+    /// as a [`Patch`], which transforms this graph into the `other`
+    /// one, if applied. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut a : Sodg<16> = Sodg::empty(256);
+    /// a.add(0);
+    /// let mut b = a.clone();
+    /// b.add(1);
+    /// b.bind(0, 1, Label::Alpha(0));
+    /// b.put(1, &Hex::from(42));
+    /// let patch = a.diff(&b);
+    /// assert_eq!(3, patch.len());
+    /// patch.apply(&mut a).unwrap();
+    /// assert_eq!(Some(1), a.kid(0, Label::Alpha(0)));
+    /// ```
+    ///
+    /// Vertices are deleted first, then added, then edges are removed
+    /// and made, and then data are put. Since data can't be removed from
+    /// a vertex, a vertex which has data here, but doesn't have them
+    /// in the `other` graph, is deleted and added again.
+    #[must_use]
+    pub fn diff(&self, other: &Self) -> Patch {
+        let mut ours = self.keys();
+        ours.sort_unstable();
+        let mut theirs = other.keys();
+        theirs.sort_unstable();
+        let reborn: HashSet<usize> = ours
+            .iter()
+            .filter(|v| other.alive(**v) && self.peek(**v).is_some() && other.peek(**v).is_none())
+            .copied()
+            .collect();
+        let gone: HashSet<usize> = ours
+            .iter()
+            .filter(|v| !other.alive(**v) || reborn.contains(*v))
+            .copied()
+            .collect();
+        let mut ops = vec![];
+        ops.extend(
+            ours.iter()
+                .filter(|v| gone.contains(*v))
+                .map(|v| PatchOp::Delete(*v)),
+        );
+        ops.extend(
+            theirs
+                .iter()
+                .filter(|v| !self.alive(**v) || reborn.contains(*v))
+                .map(|v| PatchOp::Add(*v)),
+        );
+        for v in ours.iter().filter(|v| !gone.contains(*v)) {
+            for (a, to) in self.kids(*v) {
+                if !gone.contains(to) && other.kid(*v, *a).is_none() {
+                    ops.push(PatchOp::Unbind(*v, *a));
+                }
+            }
+        }
+        for v in &theirs {
+            for (a, to) in other.kids(*v) {
+                let kept = !gone.contains(v)
+                    && !gone.contains(to)
+                    && self.alive(*v)
+                    && self.kid(*v, *a) == Some(*to);
+                if !kept {
+                    ops.push(PatchOp::Bind(*v, *to, *a));
+                }
+            }
+        }
+        for v in &theirs {
+            if let Some(d) = other.peek(*v) {
+                let same = !gone.contains(v)
+                    && self.peek(*v).is_some_and(|mine| mine.bytes() == d.bytes());
+                if !same {
+                    ops.push(PatchOp::Put(*v, d.to_vec()));
+                }
+            }
+        }
+        Patch { ops }
+    }
+}
+
+impl Patch {
+    /// Get all operations, in the order they must be applied.
+    #[must_use]
+    pub fn ops(&self) -> &[PatchOp] {
+        &self.ops
+    }
+
+    /// How many operations are there?
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.ops.len()
+    }
+
+    /// Is it empty, meaning that two graphs are the same?
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.ops.is_empty()
+    }
+
+    /// Apply all operations to the graph, one by one.
+    ///
+    /// # Errors
+    ///
+    /// If an operation refers to a vertex or an edge which is absent,
+    /// an error will be returned, leaving the operations applied
+    /// before it in the graph.
+    pub fn apply<const N: usize>(&self, g: &mut Sodg<N>) -> Result<()> {
+        let cap = g.vertices.capacity();
+        let exists = |g: &Sodg<N>, v: usize| -> Result<()> {
+            if g.alive(v) {
+                Ok(())
+            } else {
+                Err(anyhow!("Can't find ν{v}"))
+            }
+        };
+        for (pos, op) in self.ops.iter().enumerate() {
+            let done = match op {
+                PatchOp::Add(v) => {
+                    if *v < cap {
+                        g.add(*v);
+                        Ok(())
+                    } else {
+                        Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"))
+                    }
+                }
+                PatchOp::Bind(v1, v2, a) => exists(g, *v1)
+                    .and_then(|()| exists(g, *v2))
+                    .map(|()| g.bind(*v1, *v2, *a)),
+                PatchOp::Unbind(v, a) => exists(g, *v).and_then(|()| {
+                    g.unbind(*v, *a)
+                        .map(|_| ())
+                        .with_context(|| format!("There is no edge ν{v}.{a}"))
+                }),
+                PatchOp::Put(v, d) => exists(g, *v).map(|()| g.put(*v, &Hex::from_slice(d))),
+                PatchOp::Delete(v) => g.remove(*v),
+            };
+            done.with_context(|| format!("Can't apply the operation no.{pos}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Patch {
+    /// Print it as a [`crate::Script`], one command per line.
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for op in &self.ops {
+            match op {
+                PatchOp::Add(v) => writeln!(f, "ADD({v});")?,
+                PatchOp::Bind(v1, v2, a) => writeln!(f, "BIND({v1}, {v2}, {a});")?,
+                PatchOp::Unbind(v, a) => writeln!(f, "UNBIND({v}, {a});")?,
+                PatchOp::Put(v, d) => writeln!(f, "PUT({v}, {});", Hex::from_slice(d))?,
+                PatchOp::Delete(v) => writeln!(f, "DELETE({v});")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[cfg(test)]
+fn sample() -> Sodg<16> {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(2, 3, Label::from_str("foo").unwrap());
+    g.bind(3, 4, Label::Alpha(0));
+    g.put(3, &Hex::from_str_bytes("hello"));
+    g.put(4, &Hex::from(42));
+    g
+}
+
+#[test]
+fn transforms_one_graph_into_another() {
+    let before = sample();
+    let mut after: Sodg<16> = Sodg::empty(256);
+    for v in [0, 2, 3, 4, 5] {
+        after.add(v);
+    }
+    after.bind(0, 2, Label::Alpha(0));
+    after.bind(0, 5, Label::Alpha(1));
+    after.bind(2, 3, Label::from_str("foo").unwrap());
+    after.bind(5, 4, Label::Alpha(0));
+    after.put(3, &Hex::from_str_bytes("hello"));
+    after.put(5, &Hex::from_str_bytes("new"));
+    let patch = before.diff(&after);
+    let mut g = before.clone();
+    patch.apply(&mut g).unwrap();
+    g.assert_structurally_equal(&after);
+    assert!(g.diff(&after).is_empty());
+    assert!(patch.ops().contains(&PatchOp::Delete(1)));
+    assert!(patch.ops().contains(&PatchOp::Delete(4)));
+    assert!(!patch
+        .ops()
+        .iter()
+        .any(|op| matches!(op, PatchOp::Put(3, _))));
+    let mut h = before;
+    h.apply_script(&patch.to_string()).unwrap();
+    h.assert_structurally_equal(&after);
+}
+
+#[test]
+fn refuses_broken_patch() {
+    let g = sample();
+    let patch = g.diff(&Sodg::empty(256));
+    assert_eq!(5, patch.len());
+    let mut empty: Sodg<16> = Sodg::empty(256);
+    let err = patch.apply(&mut empty).unwrap_err();
+    assert!(format!("{err:#}").contains("Can't find ν0"));
+}
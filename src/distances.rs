@@ -0,0 +1,145 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Calculate the lengths of shortest paths between all pairs of
+    /// vertices, using the
+    /// [Floyd-Warshall](https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm)
+    /// algorithm.
+    ///
+    /// The function returns IDs of vertices (sorted) and the matrix, where
+    /// `matrix[i][j]` is the number of edges on the shortest path from
+    /// `ids[i]` to `ids[j]`, or `None` if there is no path. The complexity
+    /// is `O(V³)`, so it's suitable only for small graphs. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let (ids, m) = g.compute_distances();
+    /// assert_eq!(vec![0, 1], ids);
+    /// assert_eq!(vec![vec![Some(0), Some(1)], vec![None, Some(0)]], m);
+    /// ```
+    #[must_use]
+    pub fn compute_distances(&self) -> (Vec<usize>, Vec<Vec<Option<usize>>>) {
+        let mut ids = self.keys();
+        ids.sort_unstable();
+        let rows: HashMap<usize, usize> = ids.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut matrix = vec![vec![None; ids.len()]; ids.len()];
+        for (i, v) in ids.iter().enumerate() {
+            matrix[i][i] = Some(0);
+            for (_, to) in self.kids(*v) {
+                let Some(&j) = rows.get(to) else {
+                    continue;
+                };
+                if i != j {
+                    matrix[i][j] = Some(1);
+                }
+            }
+        }
+        for k in 0..ids.len() {
+            let through = matrix[k].clone();
+            for row in &mut matrix {
+                let Some(ik) = row[k] else {
+                    continue;
+                };
+                for (ij, kj) in row.iter_mut().zip(&through) {
+                    if let Some(kj) = kj {
+                        if ij.is_none_or(|d| ik + kj < d) {
+                            *ij = Some(ik + kj);
+                        }
+                    }
+                }
+            }
+        }
+        (ids, matrix)
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn measures_a_chain() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    for v in 0..4 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    let (ids, m) = g.compute_distances();
+    assert_eq!(vec![0, 1, 2, 3, 4], ids);
+    for (i, row) in m.iter().enumerate() {
+        for (j, d) in row.iter().enumerate() {
+            assert_eq!(if i <= j { Some(j - i) } else { None }, *d);
+        }
+    }
+}
+
+#[test]
+fn measures_disconnected_graph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    let (_, m) = g.compute_distances();
+    assert_eq!(Some(1), m[0][1]);
+    assert_eq!(Some(1), m[2][3]);
+    assert_eq!(None, m[0][2]);
+    assert_eq!(None, m[1][3]);
+}
+
+#[test]
+fn measures_a_cycle() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    for v in 0..4 {
+        g.bind(v, (v + 1) % 4, Label::Alpha(0));
+    }
+    let (_, m) = g.compute_distances();
+    assert_eq!(Some(3), m[1][0]);
+    assert!(m.iter().flatten().all(Option::is_some));
+}
+
+#[test]
+fn skips_collected_targets() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let (ids, m) = g.compute_distances();
+    assert_eq!(g.len(), ids.len());
+    assert!((0..ids.len()).all(|i| m[i][i] == Some(0)));
+}
@@ -18,8 +18,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{Label, Persistence, Sodg};
+use crate::{DotStyler, Hex, Label, Persistence, Sodg, BRANCH_NONE};
+use anyhow::{anyhow, Context, Result};
 use itertools::Itertools;
+use regex::Regex;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+use std::sync::LazyLock;
 
 impl<const N: usize> Sodg<N> {
     /// Print SODG as a DOT graph.
@@ -52,6 +57,20 @@ impl<const N: usize> Sodg<N> {
     /// ```
     #[must_use]
     pub fn to_dot(&self) -> String {
+        /// The styler with all defaults.
+        struct Plain;
+        impl DotStyler for Plain {}
+        self.to_dot_with(&Plain)
+    }
+
+    /// Print SODG as a DOT graph, the same way as [`Sodg::to_dot`] does,
+    /// but taking attributes of vertices and edges from the `styler`.
+    ///
+    /// Vertices, for which [`DotStyler::cluster`] returns a name, are
+    /// grouped into `subgraph cluster_N` blocks, one per name,
+    /// ordered by names.
+    #[must_use]
+    pub fn to_dot_with(&self, styler: &dyn DotStyler) -> String {
         let mut lines: Vec<String> = vec![];
         lines.push(
             "/* Render it at https://dreampuf.github.io/GraphvizOnline/ */
@@ -60,63 +79,135 @@ digraph {
   edge [fontname=\"Arial\"];"
                 .to_string(),
         );
+        let mut clusters: BTreeMap<String, Vec<usize>> = BTreeMap::new();
         for (v, vtx) in self
             .vertices
             .iter()
+            .filter(|(_, vtx)| vtx.branch != BRANCH_NONE)
             .sorted_by_key(|(v, _)| <usize>::clone(v))
         {
+            let data = if vtx.persistence == Persistence::Empty {
+                None
+            } else {
+                Some(&vtx.data)
+            };
             lines.push(format!(
-                "  v{v}[shape=circle,label=\"ν{v}\"{}]; {}",
-                if vtx.persistence == Persistence::Empty {
-                    ""
-                } else {
-                    ",color=\"#f96900\""
-                },
-                if vtx.persistence == Persistence::Empty {
-                    String::new()
-                } else {
-                    format!("/* {} */", vtx.data)
-                },
+                "  v{v}[{}]; {}",
+                styler.vertex(v, data),
+                data.map_or_else(String::new, |d| format!("/* {d} */")),
             ));
+            if let Some(name) = styler.cluster(v, data) {
+                clusters.entry(name).or_default().push(v);
+            }
             for e in vtx.edges.iter().sorted_by_key(|e| e.0) {
                 lines.push(format!(
-                    "  v{v} -> v{} [label=\"{}\"{}{}];",
+                    "  v{v} -> v{} [{}];",
                     e.1,
-                    e.0,
-                    match e.0 {
-                        Label::Greek(g) => {
-                            if *g == 'ρ' || *g == 'σ' {
-                                ",color=gray,fontcolor=gray"
-                            } else {
-                                ""
-                            }
-                        }
-                        _ => {
-                            ""
-                        }
-                    },
-                    match e.0 {
-                        Label::Greek(g) => {
-                            if *g == 'π' {
-                                ",style=dashed"
-                            } else {
-                                ""
-                            }
-                        }
-                        _ => {
-                            ""
-                        }
-                    }
+                    styler.edge(v, *e.0, *e.1)
                 ));
             }
         }
+        for (i, (name, vs)) in clusters.iter().enumerate() {
+            lines.push(format!(
+                "  subgraph cluster_{i} {{ label=\"{name}\"; {} }}",
+                vs.iter().map(|v| format!("v{v};")).join(" ")
+            ));
+        }
         lines.push("}\n".to_string());
         lines.join("\n")
     }
-}
 
-#[cfg(test)]
-use crate::Hex;
+    /// Make a new [`Sodg`] from a DOT graph, as it is printed by [`Sodg::to_dot`].
+    ///
+    /// Only a subset of DOT is supported: a single `digraph { ... }` with one
+    /// statement per line, where vertices are named either `v42` or `42`.
+    /// Attributes of vertices are ignored, while the data of a vertex is taken
+    /// from the `/* ... */` comment after it, if present. Edges must have their
+    /// labels either in their own `label` attribute or in the `label` of
+    /// the `edge [...]` statement. Single-line `subgraph` blocks are skipped.
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let g : Sodg<16> = Sodg::from_dot(
+    ///     "digraph {\n  v0 -> v1 [label=\"foo\"];\n}", 256
+    /// ).unwrap();
+    /// assert_eq!(1, g.kid(0, Label::from_str("foo").unwrap()).unwrap());
+    /// ```
+    ///
+    /// The `cap` is the capacity of the graph, as in [`Sodg::empty`].
+    ///
+    /// # Errors
+    ///
+    /// If the text can't be parsed or an ID of a vertex doesn't fit
+    /// into the `cap`, an error will be returned.
+    pub fn from_dot(dot: &str, cap: usize) -> Result<Self> {
+        static COMMENTS: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("(?s)^\\s*/\\*.*?\\*/").unwrap());
+        static DEFAULTS: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^(node|edge|graph) *\\[(.*)\\];?$").unwrap());
+        static VERTEX: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new("^v?([0-9]+) *(\\[.*\\])? *;? *(/\\* *(.*?) *\\*/)?$").unwrap()
+        });
+        static EDGE: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new("^v?([0-9]+) *-> *v?([0-9]+) *(\\[(.*)\\])? *;?$").unwrap()
+        });
+        static SUBGRAPH: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^subgraph [^{]*\\{.*\\}$").unwrap());
+        let text = COMMENTS.replace_all(dot, "");
+        let body = text
+            .trim()
+            .strip_prefix("digraph")
+            .map(str::trim_start)
+            .and_then(|t| t.strip_prefix('{'))
+            .and_then(|t| t.strip_suffix('}'))
+            .context("A DOT graph must look like 'digraph { ... }'")?;
+        let mut g = Self::empty(cap);
+        let mut label = None;
+        for (pos, line) in body.lines().map(str::trim).enumerate() {
+            if line.is_empty() || SUBGRAPH.is_match(line) {
+                continue;
+            }
+            if let Some(caps) = DEFAULTS.captures(line) {
+                if &caps[1] == "edge" {
+                    label = Self::dot_label(&caps[2]).or(label);
+                }
+            } else if let Some(caps) = EDGE.captures(line) {
+                let v1 = usize::from_str(&caps[1])?;
+                let v2 = usize::from_str(&caps[2])?;
+                let a = caps
+                    .get(4)
+                    .and_then(|m| Self::dot_label(m.as_str()))
+                    .or_else(|| label.clone())
+                    .with_context(|| format!("The edge ν{v1} -> ν{v2} has no label"))?;
+                g.try_add_if_absent(v1)?;
+                g.try_add_if_absent(v2)?;
+                g.bind(v1, v2, Label::from_str(&a)?);
+            } else if let Some(caps) = VERTEX.captures(line) {
+                let v = usize::from_str(&caps[1])?;
+                g.try_add_if_absent(v)?;
+                if let Some(d) = caps.get(4) {
+                    g.put(
+                        v,
+                        &Hex::from_str(d.as_str())
+                            .with_context(|| format!("Can't parse data of ν{v}"))?,
+                    );
+                }
+            } else {
+                return Err(anyhow!("Can't parse DOT line no.{}: '{line}'", pos + 1));
+            }
+        }
+        Ok(g)
+    }
+
+    /// Find the `label` in the list of DOT attributes.
+    fn dot_label(attrs: &str) -> Option<String> {
+        static LABEL: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("(?:^|[ ,\\[])label *= *\"([^\"]*)\"").unwrap());
+        LABEL.captures(attrs).map(|cap| cap[1].to_string())
+    }
+}
 
 #[test]
 fn simple_graph_to_dot() {
@@ -128,3 +219,89 @@ fn simple_graph_to_dot() {
     let dot = g.to_dot();
     assert!(dot.contains("shape=circle,label=\"ν0\""));
 }
+
+#[test]
+fn reads_what_it_prints() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(2, 0, Label::from_str("ρ").unwrap());
+    g.put(1, &Hex::from_str_bytes("hello"));
+    let after: Sodg<16> = Sodg::from_dot(&g.to_dot(), 256).unwrap();
+    after.assert_structurally_equal(&g);
+}
+
+#[test]
+fn rejects_too_big_vertex() {
+    let r: Result<Sodg<16>> =
+        Sodg::from_dot("digraph {\n  v100000000 -> v1 [label=\"foo\"];\n}", 16);
+    assert!(r.is_err());
+    let r: Result<Sodg<16>> = Sodg::from_dot("digraph {\n  v16;\n}", 16);
+    assert!(r.is_err());
+}
+
+#[test]
+fn prints_styled_dot() {
+    struct ByData;
+    impl DotStyler for ByData {
+        fn vertex(&self, v: usize, data: Option<&Hex>) -> String {
+            format!(
+                "shape={},label=\"{v}\"",
+                if data.is_some() { "box" } else { "point" }
+            )
+        }
+
+        fn edge(&self, _from: usize, a: Label, _to: usize) -> String {
+            format!("label=\"{a}\",color=red")
+        }
+
+        fn cluster(&self, v: usize, _data: Option<&Hex>) -> Option<String> {
+            (v > 0).then(|| "full".to_string())
+        }
+    }
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.put(1, &Hex::from_str_bytes("hello"));
+    g.put(2, &Hex::from_str_bytes("world"));
+    let dot = g.to_dot_with(&ByData);
+    assert!(dot.contains("v0[shape=point,label=\"0\"];"));
+    assert!(dot.contains("v1[shape=box,label=\"1\"];"));
+    assert!(dot.contains("v0 -> v1 [label=\"foo\",color=red];"));
+    assert!(dot.contains("subgraph cluster_0 { label=\"full\"; v1; v2; }"));
+    let after: Sodg<16> = Sodg::from_dot(&dot, 256).unwrap();
+    after.assert_structurally_equal(&g);
+}
+
+#[test]
+fn reads_handmade_dot() {
+    let g: Sodg<16> = Sodg::from_dot(
+        "
+        digraph {
+          node [shape=circle];
+          edge [label=\"next\"];
+          1 [label=\"first\"]; /* 00-2A */
+          1 -> 2;
+          2 -> 3 [color=red,label=\"last\"];
+        }
+        ",
+        256,
+    )
+    .unwrap();
+    assert_eq!(3, g.len());
+    assert_eq!(2, g.kid(1, Label::from_str("next").unwrap()).unwrap());
+    assert_eq!(3, g.kid(2, Label::from_str("last").unwrap()).unwrap());
+    assert_eq!("00-2A", g.vertices.get(1).unwrap().data.print());
+}
+
+#[test]
+fn rejects_broken_dot() {
+    assert!(Sodg::<16>::from_dot("graph { 1 -- 2 }", 256).is_err());
+    assert!(Sodg::<16>::from_dot("digraph { 1 -> 2; }", 256).is_err());
+    assert!(Sodg::<16>::from_dot("digraph {\n  hello world\n}", 256).is_err());
+}
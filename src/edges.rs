@@ -0,0 +1,98 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg, BRANCH_NONE};
+
+impl<const N: usize> Sodg<N> {
+    /// Visit all edges of the graph, one by one, without allocating
+    /// any memory on the heap.
+    ///
+    /// The `visitor` gets the departure vertex, the arrival vertex, and the
+    /// label of each edge. It returns `true` to continue or `false` to stop.
+    /// The function returns `true` if all edges were visited. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 0, Label::Alpha(0));
+    /// let mut total = 0;
+    /// assert!(g.stream_edges(|_, _, _| { total += 1; true }));
+    /// assert_eq!(2, total);
+    /// ```
+    pub fn stream_edges<F: FnMut(usize, usize, &Label) -> bool>(&self, mut visitor: F) -> bool {
+        for (v, vtx) in self.vertices.iter() {
+            if vtx.branch == BRANCH_NONE {
+                continue;
+            }
+            for (a, to) in &vtx.edges {
+                if !visitor(v, *to, a) {
+                    return false;
+                }
+            }
+        }
+        true
+    }
+}
+
+#[test]
+fn streams_same_edges_as_kids() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(3, 0, Label::Alpha(7));
+    let mut expected = vec![];
+    for v in g.keys() {
+        for (a, to) in g.kids(v) {
+            expected.push((v, *to, *a));
+        }
+    }
+    expected.sort();
+    let mut seen = vec![];
+    assert!(g.stream_edges(|v, to, a| {
+        seen.push((v, to, *a));
+        true
+    }));
+    seen.sort();
+    assert_eq!(expected, seen);
+}
+
+#[test]
+fn stops_early() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    for v in 0..3 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    let mut total = 0;
+    assert!(!g.stream_edges(|_, _, _| {
+        total += 1;
+        total < 2
+    }));
+    assert_eq!(2, total);
+}
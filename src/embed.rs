@@ -0,0 +1,120 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Copy the vertex `guest_root` of the `guest` graph, together with
+    /// everything reachable from it, into this graph, and bind it to
+    /// the vertex `parent` with the label `a`.
+    ///
+    /// All copied vertices get new IDs, which are not used in this
+    /// graph yet. Data are copied too. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut host : Sodg<16> = Sodg::empty(256);
+    /// host.add(0);
+    /// let mut guest : Sodg<16> = Sodg::empty(256);
+    /// guest.add(0);
+    /// guest.add(1);
+    /// guest.bind(0, 1, Label::Alpha(0));
+    /// host.embed_as_subgraph(0, Label::from_str("plugin").unwrap(), &guest, 0).unwrap();
+    /// let root = host.kid(0, Label::from_str("plugin").unwrap()).unwrap();
+    /// assert!(host.kid(root, Label::Alpha(0)).is_some());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If `parent` or `guest_root` is absent, or `parent` already
+    /// has an edge with this label, an error will be returned.
+    pub fn embed_as_subgraph(
+        &mut self,
+        parent: usize,
+        a: Label,
+        guest: &Self,
+        guest_root: usize,
+    ) -> Result<()> {
+        if !self.alive(parent) {
+            return Err(anyhow!("Can't find ν{parent}"));
+        }
+        if let Some(k) = self.kid(parent, a) {
+            return Err(anyhow!(
+                "The edge ν{parent}.{a} already exists, pointing to ν{k}"
+            ));
+        }
+        if !guest.alive(guest_root) {
+            return Err(anyhow!("Can't find ν{guest_root} in the guest"));
+        }
+        let sub = guest.slice(guest_root)?;
+        let mut keys = sub.keys();
+        keys.sort_unstable();
+        let mut remap = HashMap::new();
+        for v in keys {
+            let id = self.next_id();
+            self.add(id);
+            remap.insert(v, id);
+        }
+        for (v, id) in &remap {
+            for (k, to) in sub.kids(*v) {
+                self.bind(*id, remap[to], *k);
+            }
+            if let Some(d) = sub.peek(*v) {
+                self.put(*id, d);
+            }
+        }
+        self.bind(parent, remap[&guest_root], a);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::Hex;
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn embeds_guest_into_host() {
+    let mut host: Sodg<16> = Sodg::empty(256);
+    host.add(0);
+    host.add(1);
+    host.bind(0, 1, Label::from_str("foo").unwrap());
+    let mut guest: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        guest.add(v);
+    }
+    guest.bind(0, 1, Label::Alpha(0));
+    guest.bind(1, 2, Label::Alpha(0));
+    guest.put(2, &Hex::from(42));
+    let plugin = Label::from_str("plugin").unwrap();
+    host.embed_as_subgraph(0, plugin, &guest, 0).unwrap();
+    assert_eq!(5, host.len());
+    assert_eq!(Some(1), host.kid(0, Label::from_str("foo").unwrap()));
+    let root = host.kid(0, plugin).unwrap();
+    assert!(![0, 1].contains(&root));
+    let mid = host.kid(root, Label::Alpha(0)).unwrap();
+    let leaf = host.kid(mid, Label::Alpha(0)).unwrap();
+    assert_eq!(42, host.data(leaf).unwrap().to_i64().unwrap());
+    assert!(host.embed_as_subgraph(0, plugin, &guest, 0).is_err());
+}
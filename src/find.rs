@@ -0,0 +1,204 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use anyhow::{anyhow, Context, Result};
+use std::collections::{HashSet, VecDeque};
+use std::str::FromStr;
+
+/// A single step of a search pattern.
+enum Step {
+    Exact(Label),
+    Any,
+    Deep,
+}
+
+impl<const N: usize> Sodg<N> {
+    /// Find a vertex by the locator, which is a dot-separated list of
+    /// labels, following the edges from the `start` one by one.
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// assert_eq!(2, g.find(0, "foo.α0").unwrap());
+    /// assert_eq!(0, g.find(0, "").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is no `start` vertex, or a label can't be parsed, or
+    /// an edge is missing, an error will be returned, telling where
+    /// exactly the locator broke.
+    pub fn find(&self, start: usize, path: &str) -> Result<usize> {
+        if !self.alive(start) {
+            return Err(anyhow!("Can't find ν{start}"));
+        }
+        let mut v = start;
+        if path.is_empty() {
+            return Ok(v);
+        }
+        let mut passed: Vec<&str> = vec![];
+        for part in path.split('.') {
+            if part.is_empty() {
+                return Err(anyhow!(
+                    "Empty label after '{}' in '{path}'",
+                    passed.join(".")
+                ));
+            }
+            let a = Label::from_str(part)
+                .with_context(|| format!("Can't parse '{part}' in '{path}'"))?;
+            v = self.kid(v, a).with_context(|| {
+                format!(
+                    "There is no edge ν{v}.{a}, after '{}' from ν{start} in '{path}'",
+                    passed.join(".")
+                )
+            })?;
+            passed.push(part);
+        }
+        Ok(v)
+    }
+
+    /// Find all vertices reachable from the `start` by the paths matching
+    /// the pattern, sorted by their IDs.
+    ///
+    /// The pattern is a dot-separated list of steps, the same way as in
+    /// [`Sodg::find`], where `*` matches any label and `**` matches
+    /// any number of edges, including none. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// for v in 0..4 {
+    ///     g.add(v);
+    /// }
+    /// g.bind(0, 1, Label::from_str("book").unwrap());
+    /// g.bind(1, 2, Label::from_str("price").unwrap());
+    /// g.bind(0, 3, Label::from_str("price").unwrap());
+    /// assert_eq!(vec![2, 3], g.search(0, "**.price").unwrap());
+    /// assert_eq!(vec![2], g.search(0, "*.price").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is no `start` vertex or the pattern can't be
+    /// parsed, an error will be returned.
+    pub fn search(&self, start: usize, pattern: &str) -> Result<Vec<usize>> {
+        if !self.alive(start) {
+            return Err(anyhow!("Can't find ν{start}"));
+        }
+        let mut steps = vec![];
+        if !pattern.is_empty() {
+            for part in pattern.split('.') {
+                steps.push(match part {
+                    "" => return Err(anyhow!("Empty step in '{pattern}'")),
+                    "*" => Step::Any,
+                    "**" => Step::Deep,
+                    _ => Step::Exact(
+                        Label::from_str(part)
+                            .with_context(|| format!("Can't parse '{part}' in '{pattern}'"))?,
+                    ),
+                });
+            }
+        }
+        let mut found = HashSet::new();
+        let mut seen = HashSet::from([(start, 0)]);
+        let mut todo = VecDeque::from([(start, 0)]);
+        while let Some((v, pos)) = todo.pop_front() {
+            let mut next = vec![];
+            match steps.get(pos) {
+                None => {
+                    found.insert(v);
+                }
+                Some(Step::Exact(a)) => {
+                    if let Some(to) = self.kid(v, *a) {
+                        next.push((to, pos + 1));
+                    }
+                }
+                Some(Step::Any) => {
+                    next.extend(self.kids(v).map(|(_, to)| (*to, pos + 1)));
+                }
+                Some(Step::Deep) => {
+                    next.push((v, pos + 1));
+                    next.extend(self.kids(v).map(|(_, to)| (*to, pos)));
+                }
+            }
+            for state in next {
+                if seen.insert(state) {
+                    todo.push_back(state);
+                }
+            }
+        }
+        let mut all: Vec<usize> = found.into_iter().collect();
+        all.sort_unstable();
+        Ok(all)
+    }
+}
+
+#[test]
+fn finds_by_locator() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(1, 2, Label::from_str("bar").unwrap());
+    g.bind(2, 3, Label::Alpha(0));
+    assert_eq!(3, g.find(0, "foo.bar.α0").unwrap());
+    assert_eq!(3, g.find(1, "bar.α0").unwrap());
+    let err = g.find(0, "foo.bar.α1").unwrap_err();
+    assert_eq!(
+        "There is no edge ν2.α1, after 'foo.bar' from ν0 in 'foo.bar.α1'",
+        err.to_string()
+    );
+    assert!(g.find(0, "foo..bar").is_err());
+    assert!(g.find(7, "foo").is_err());
+}
+
+#[test]
+fn searches_by_pattern() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    let price = Label::from_str("price").unwrap();
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(0, 2, Label::from_str("bar").unwrap());
+    g.bind(1, 3, price);
+    g.bind(2, 4, Label::Alpha(0));
+    g.bind(4, 5, price);
+    g.bind(5, 0, Label::Alpha(0));
+    assert_eq!(vec![3, 5], g.search(0, "**.price").unwrap());
+    assert_eq!(vec![3], g.search(0, "*.price").unwrap());
+    assert_eq!(vec![5], g.search(2, "α0.price").unwrap());
+    assert_eq!(vec![1, 2], g.search(0, "*").unwrap());
+    assert_eq!(vec![0, 1, 2, 3, 4, 5], g.search(0, "**").unwrap());
+    assert_eq!(vec![3, 5], g.search(0, "bar.**.price").unwrap());
+    assert_eq!(vec![0], g.search(0, "").unwrap());
+    assert!(g.search(0, "foo..bar").is_err());
+    assert!(g.search(0, "much_too_long").is_err());
+}
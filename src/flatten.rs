@@ -0,0 +1,223 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Sodg};
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Collect data of all vertices reachable from the `root`, in the
+    /// order of a depth-first traversal: a vertex goes first, then its
+    /// kids, in the order of the labels of their edges.
+    ///
+    /// Vertices without data are skipped, each vertex is visited only once.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 2, Label::Alpha(1));
+    /// g.put(1, &Hex::from_str_bytes("a"));
+    /// g.put(2, &Hex::from_str_bytes("b"));
+    /// assert_eq!(vec![b"a".to_vec(), b"b".to_vec()], g.flatten(0).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `root` is absent, an error will be returned.
+    pub fn flatten(&self, root: usize) -> Result<Vec<Vec<u8>>> {
+        Ok(self
+            .flatten_hex(root)?
+            .into_iter()
+            .map(|d| d.to_vec())
+            .collect())
+    }
+
+    /// Collect data of all vertices reachable from the `root`,
+    /// the same way as [`Sodg::flatten`] does, but as [`Hex`].
+    ///
+    /// # Errors
+    ///
+    /// If the `root` is absent, an error will be returned.
+    pub fn flatten_hex(&self, root: usize) -> Result<Vec<Hex>> {
+        if !self.alive(root) {
+            return Err(anyhow!("Can't find ν{root}"));
+        }
+        let mut seen = HashSet::from([root]);
+        let mut todo = vec![root];
+        let mut found = vec![];
+        while let Some(v) = todo.pop() {
+            if let Some(d) = self.peek(v) {
+                found.push(d.clone());
+            }
+            let start = todo.len();
+            for (_, to) in self.live_kids(v) {
+                if seen.insert(*to) {
+                    todo.push(*to);
+                }
+            }
+            todo[start..].reverse();
+        }
+        Ok(found)
+    }
+
+    /// Fold data of all vertices reachable from the `root`, in the
+    /// same order as [`Sodg::flatten`] collects them.
+    ///
+    /// The `root` itself is included, if it has data. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.put(0, &Hex::from_str_bytes("ab"));
+    /// g.put(1, &Hex::from_str_bytes("c"));
+    /// let total = g.aggregate_data(0, 0, |acc, d| acc + d.len()).unwrap();
+    /// assert_eq!(3, total);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `root` is absent, an error will be returned.
+    pub fn aggregate_data<T, F>(&self, root: usize, init: T, combine: F) -> Result<T>
+    where
+        F: Fn(T, &[u8]) -> T,
+    {
+        Ok(self
+            .flatten_hex(root)?
+            .iter()
+            .fold(init, |acc, d| combine(acc, d.bytes())))
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+fn collected() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.bind(3, 2, Label::Alpha(1));
+    g.put(1, &Hex::from_str_bytes("a"));
+    g.put(3, &Hex::from_str_bytes("c"));
+    g.data(3);
+    g
+}
+
+#[test]
+fn flattens_tree_in_order() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..7 {
+        g.add(v);
+    }
+    for v in 0..3 {
+        g.bind(v, 2 * v + 1, Label::Alpha(0));
+        g.bind(v, 2 * v + 2, Label::Alpha(1));
+    }
+    for v in 3..7 {
+        g.put(v, &Hex::from(i64::try_from(v).unwrap()));
+    }
+    g.put(0, &Hex::from(0));
+    let found: Vec<i64> = g
+        .flatten_hex(0)
+        .unwrap()
+        .iter()
+        .map(|d| d.to_i64().unwrap())
+        .collect();
+    assert_eq!(vec![0, 3, 4, 5, 6], found);
+}
+
+#[test]
+fn flattens_chain() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+        g.put(v, &Hex::from_str_bytes(&format!("x{v}")));
+    }
+    for v in 0..3 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    g.bind(3, 0, Label::Alpha(0));
+    assert_eq!(
+        vec![
+            b"x2".to_vec(),
+            b"x3".to_vec(),
+            b"x0".to_vec(),
+            b"x1".to_vec()
+        ],
+        g.flatten(2).unwrap()
+    );
+}
+
+#[test]
+fn sums_numbers_in_chain() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+        g.put(v, &Hex::from(i64::try_from(v).unwrap() * 10));
+    }
+    for v in 0..4 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    let sum = g
+        .aggregate_data(0, 0, |acc, d| acc + Hex::from_slice(d).to_i64().unwrap())
+        .unwrap();
+    assert_eq!(100, sum);
+    let blob = g
+        .aggregate_data(3, vec![], |mut acc, d| {
+            acc.push(d[7]);
+            acc
+        })
+        .unwrap();
+    assert_eq!(vec![30, 40], blob);
+}
+
+#[test]
+fn aggregates_nothing() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    assert_eq!(42, g.aggregate_data(0, 42, |acc, d| acc + d.len()).unwrap());
+    assert!(g.aggregate_data(7, 0, |acc, _| acc).is_err());
+}
+
+#[test]
+fn skips_collected_vertices() {
+    let g = collected();
+    assert_eq!(vec![b"a".to_vec()], g.flatten(0).unwrap());
+}
+
+#[test]
+fn aggregates_only_live_data() {
+    let g = collected();
+    assert_eq!(1, g.aggregate_data(0, 0, |acc, d| acc + d.len()).unwrap());
+}
@@ -0,0 +1,181 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Persistence, Sodg, BRANCH_NONE};
+#[cfg(debug_assertions)]
+use log::trace;
+use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
+use std::mem::size_of;
+
+impl<const N: usize> Sodg<N> {
+    /// Release memory held by vertices that are not in the graph anymore
+    /// and shrink internal maps to fit their contents, returning
+    /// the approximate number of bytes reclaimed.
+    ///
+    /// It makes sense to call it after a massive removal of vertices,
+    /// for example by [`Sodg::prune_isolated`]. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.put(1, &Hex::from(42));
+    /// g.prune_empty_vertices();
+    /// g.gc();
+    /// assert_eq!(1, g.len());
+    /// ```
+    pub fn gc(&mut self) -> usize {
+        let mut freed = 0;
+        for (_, vtx) in self.vertices.iter_mut() {
+            if vtx.branch != BRANCH_NONE {
+                continue;
+            }
+            if let Hex::Vector(d) = &vtx.data {
+                freed += d.capacity();
+            }
+            vtx.data = Hex::empty();
+            vtx.persistence = Persistence::Empty;
+            vtx.edges.clear();
+        }
+        let alive: HashSet<usize> = self.keys().into_iter().collect();
+        self.names.retain(|v, _| alive.contains(v));
+        self.named.retain(|_, v| alive.contains(v));
+        self.attrs.retain(|(v, _), _| alive.contains(v));
+        self.watchers.retain(|v, _| alive.contains(v));
+        self.traces.get_mut().retain(|(v, _), _| alive.contains(v));
+        freed += Self::shrink(&mut self.names);
+        freed += Self::shrink(&mut self.named);
+        freed += Self::shrink(&mut self.attrs);
+        freed += Self::shrink(&mut self.watchers);
+        freed += Self::shrink(self.traces.get_mut());
+        if let Some(idx) = &mut self.index {
+            idx.retain(|(v, _), _| alive.contains(v));
+            freed += Self::shrink(idx);
+        }
+        if let Some(refs) = &mut self.refs {
+            refs.retain(|v, _| alive.contains(v));
+            freed += Self::shrink(refs);
+        }
+        #[cfg(debug_assertions)]
+        trace!("#gc: approximately {freed} bytes reclaimed");
+        freed
+    }
+
+    /// Remove all vertices that are not reachable from `root`, returning
+    /// the number of vertices removed. If there is no `root` in the graph,
+    /// all vertices are removed. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(2, 1, Label::Alpha(0));
+    /// assert_eq!(1, g.collect(0));
+    /// assert_eq!(2, g.len());
+    /// ```
+    ///
+    /// In order to release the memory held by the removed vertices,
+    /// call [`Sodg::gc`] afterwards.
+    pub fn collect(&mut self, root: usize) -> usize {
+        let seen = self.reachable_from(root).unwrap_or_default();
+        let garbage: Vec<usize> = self
+            .keys()
+            .into_iter()
+            .filter(|v| !seen.contains(v))
+            .collect();
+        for v in &garbage {
+            self.erase(*v);
+        }
+        #[cfg(debug_assertions)]
+        trace!(
+            "#collect: {} vertices not reachable from ν{root} removed",
+            garbage.len()
+        );
+        garbage.len()
+    }
+
+    /// Shrink the map to fit, returning the approximate number
+    /// of bytes released.
+    fn shrink<K: Eq + Hash, V>(map: &mut HashMap<K, V>) -> usize {
+        let before = map.capacity();
+        map.shrink_to_fit();
+        (before - map.capacity()) * (size_of::<K>() + size_of::<V>())
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn shrinks_after_pruning() {
+    let mut g: Sodg<16> = Sodg::empty(10000);
+    for v in 0..10000 {
+        g.add(v);
+        g.set_vertex_label(v, &format!("v{v}")).unwrap();
+        if v % 10 == 0 {
+            g.put(v, &Hex::from(42));
+        }
+    }
+    assert_eq!(9000, g.prune_empty_vertices());
+    assert!(g.gc() > 0);
+    assert_eq!(1000, g.len());
+    assert_eq!(Some("v20"), g.vertex_label(20));
+    assert_eq!(0, g.gc());
+}
+
+#[test]
+fn releases_collected_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.put(0, &Hex::from_vec(vec![7; 1000]));
+    g.data(0);
+    assert_eq!(0, g.len());
+    assert!(g.gc() >= 1000);
+    assert!(g.vertices.get(0).unwrap().edges.is_empty());
+}
+
+#[test]
+fn collects_unreachable_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(3, 4, Label::Alpha(0));
+    g.bind(4, 1, Label::Alpha(0));
+    g.put(4, &Hex::from(42));
+    assert_eq!(3, g.collect(0));
+    assert_eq!(vec![0, 1, 2], {
+        let mut keys = g.keys();
+        keys.sort_unstable();
+        keys
+    });
+    assert_eq!(0, g.collect(0));
+    assert_eq!(3, g.collect(42));
+    assert!(g.is_empty());
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{Context, Result};
+use serde_json::{json, Value};
+
+impl<const N: usize> Sodg<N> {
+    /// Send the graph to a [Gephi](https://gephi.org) streaming server,
+    /// using its Graph Streaming API.
+    ///
+    /// Each vertex is sent as an `an` ("add node") event and each edge
+    /// as an `ae` ("add edge") event, all of them in one HTTP POST request.
+    /// The `endpoint` is usually something like
+    /// `http://localhost:8080/workspace1?operation=updateGraph`.
+    ///
+    /// This function is available only with the `gephi` feature.
+    ///
+    /// # Errors
+    ///
+    /// If the server is not reachable or rejects the request,
+    /// an error will be returned.
+    pub fn stream_to_gephi(&self, endpoint: &str) -> Result<()> {
+        let body = self.gephi_events().join("\r\n");
+        ureq::post(endpoint)
+            .set("Content-Type", "application/json")
+            .send_string(&body)
+            .with_context(|| format!("Can't stream to Gephi at {endpoint}"))?;
+        Ok(())
+    }
+
+    /// Make a list of Gephi streaming events, one JSON object per vertex
+    /// or edge, with vertices going first.
+    fn gephi_events(&self) -> Vec<String> {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut events = vec![];
+        for v in &keys {
+            let mut node = json!({ "label": format!("ν{v}") });
+            if let Some(d) = self.peek(*v) {
+                node["data"] = Value::String(d.print());
+            }
+            events.push(json!({ "an": { v.to_string(): node } }).to_string());
+        }
+        for v in &keys {
+            let mut kids: Vec<_> = self.kids(*v).collect();
+            kids.sort();
+            for (a, to) in kids {
+                events.push(
+                    json!({
+                        "ae": {
+                            format!("{v}.{a}"): {
+                                "source": v.to_string(),
+                                "target": to.to_string(),
+                                "directed": true,
+                                "label": a.to_string(),
+                            }
+                        }
+                    })
+                    .to_string(),
+                );
+            }
+        }
+        events
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn makes_node_and_edge_events() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    let events = g.gephi_events();
+    assert_eq!(3, events.len());
+    assert!(events[0].starts_with("{\"an\":{\"0\":"));
+    assert!(events[2].starts_with("{\"ae\":{\"0.α0\":"));
+    assert!(events[2].contains("\"target\":\"1\""));
+}
+
+#[test]
+fn fails_on_dead_endpoint() {
+    let g: Sodg<16> = Sodg::empty(256);
+    assert!(g.stream_to_gephi("http://127.0.0.1:1/workspace1").is_err());
+}
@@ -0,0 +1,254 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Sodg};
+use anyhow::{anyhow, Context, Result};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use regex::Regex;
+use std::str::FromStr;
+use std::sync::LazyLock;
+use sxd_document::dom::{ChildOfElement, ChildOfRoot, Element};
+use xml_builder::{XMLBuilder, XMLElement, XMLVersion};
+
+impl<const N: usize> Sodg<N> {
+    /// Print the graph as [GraphML](http://graphml.graphdrawing.org/),
+    /// which can be opened in Gephi or yEd.
+    ///
+    /// Each vertex `νi` becomes a `<node id="vi">`, with its data encoded
+    /// in base64 as the `data` attribute. Each edge becomes an `<edge>`,
+    /// with its label as the `label` attribute. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.put(0, &Hex::from_str_bytes("hello"));
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// let xml = g.to_graphml().unwrap();
+    /// assert!(xml.contains("<data key=\"data\">aGVsbG8=</data>"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If it's impossible to print it to XML, an [`Err`] may be returned.
+    pub fn to_graphml(&self) -> Result<String> {
+        let mut xml = XMLBuilder::new()
+            .version(XMLVersion::XML1_0)
+            .encoding("UTF-8".into())
+            .build();
+        let mut root = XMLElement::new("graphml");
+        root.add_attribute("xmlns", "http://graphml.graphdrawing.org/xmlns");
+        for (id, target) in [("data", "node"), ("label", "edge")] {
+            let mut key = XMLElement::new("key");
+            key.add_attribute("id", id);
+            key.add_attribute("for", target);
+            key.add_attribute("attr.name", id);
+            key.add_attribute("attr.type", "string");
+            root.add_child(key)?;
+        }
+        let mut graph = XMLElement::new("graph");
+        graph.add_attribute("id", "G");
+        graph.add_attribute("edgedefault", "directed");
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        for v in &keys {
+            let mut node = XMLElement::new("node");
+            node.add_attribute("id", &format!("v{v}"));
+            if let Some(d) = self.peek(*v) {
+                node.add_child(Self::graphml_data("data", &STANDARD.encode(d.bytes()))?)?;
+            }
+            graph.add_child(node)?;
+        }
+        for v in &keys {
+            let mut kids: Vec<(Label, usize)> = self.kids(*v).map(|(a, to)| (*a, *to)).collect();
+            kids.sort_unstable();
+            for (a, to) in kids {
+                let mut edge = XMLElement::new("edge");
+                edge.add_attribute("source", &format!("v{v}"));
+                edge.add_attribute("target", &format!("v{to}"));
+                edge.add_child(Self::graphml_data("label", &a.to_string())?)?;
+                graph.add_child(edge)?;
+            }
+        }
+        root.add_child(graph)?;
+        xml.set_root_element(root);
+        let mut writer: Vec<u8> = Vec::new();
+        xml.generate(&mut writer)?;
+        Ok(std::str::from_utf8(&writer)?.to_string())
+    }
+
+    /// Make a new [`Sodg`] from an XML document, as it is printed
+    /// by [`Sodg::to_graphml`].
+    ///
+    /// Identifiers of nodes must end with a number, like `v42` or `n42`,
+    /// which becomes the number of the vertex. The `data` attribute
+    /// of a node is decoded from base64, while the `label` attribute of an
+    /// edge is parsed as a [`Label`]. Other attributes are ignored.
+    ///
+    /// # Errors
+    ///
+    /// If the document is broken, there is no room for all vertices
+    /// in `cap`, or an edge refers to a node that is not declared,
+    /// an error will be returned.
+    pub fn from_graphml(xml: &str, cap: usize) -> Result<Self> {
+        let package =
+            sxd_document::parser::parse(xml).with_context(|| "Can't parse GraphML document")?;
+        let doc = package.as_document();
+        let root = doc
+            .root()
+            .children()
+            .into_iter()
+            .find_map(|c| match c {
+                ChildOfRoot::Element(e) => Some(e),
+                _ => None,
+            })
+            .with_context(|| "There is no root element in GraphML")?;
+        let graph = Self::graphml_kids(root, "graph")
+            .into_iter()
+            .next()
+            .with_context(|| "There is no <graph> in GraphML")?;
+        let mut g = Self::empty(cap);
+        for node in Self::graphml_kids(graph, "node") {
+            let v = Self::graphml_id(node.attribute_value("id"))?;
+            if v >= cap {
+                return Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"));
+            }
+            g.add_if_absent(v);
+            if let Some(d) = Self::graphml_value(node, "data") {
+                let bytes = STANDARD
+                    .decode(d.trim())
+                    .with_context(|| format!("Can't decode data of ν{v}"))?;
+                g.put(v, &Hex::from_vec(bytes));
+            }
+        }
+        for edge in Self::graphml_kids(graph, "edge") {
+            let v1 = Self::graphml_id(edge.attribute_value("source"))?;
+            let v2 = Self::graphml_id(edge.attribute_value("target"))?;
+            let label = Self::graphml_value(edge, "label")
+                .with_context(|| format!("There is no label at the edge ν{v1} → ν{v2}"))?;
+            for v in [v1, v2] {
+                if v >= cap || !g.alive(v) {
+                    return Err(anyhow!(
+                        "The edge ν{v1} → ν{v2} refers to ν{v}, which is not declared as a node"
+                    ));
+                }
+            }
+            g.bind(v1, v2, Label::from_str(label.trim())?);
+        }
+        Ok(g)
+    }
+
+    /// Make a `<data>` element with the given key and text, escaping
+    /// the text, since the XML builder doesn't do it.
+    fn graphml_data(key: &str, text: &str) -> Result<XMLElement> {
+        let mut data = XMLElement::new("data");
+        data.add_attribute("key", key);
+        data.add_text(
+            text.replace('&', "&amp;")
+                .replace('<', "&lt;")
+                .replace('>', "&gt;"),
+        )?;
+        Ok(data)
+    }
+
+    /// Find all kids of the element with the given local name.
+    fn graphml_kids<'d>(e: Element<'d>, name: &str) -> Vec<Element<'d>> {
+        e.children()
+            .into_iter()
+            .filter_map(|c| match c {
+                ChildOfElement::Element(k) if k.name().local_part() == name => Some(k),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Find the text of the `<data>` kid with the given key.
+    fn graphml_value(e: Element<'_>, key: &str) -> Option<String> {
+        Self::graphml_kids(e, "data")
+            .into_iter()
+            .find(|d| d.attribute_value("key") == Some(key))
+            .map(|d| {
+                d.children()
+                    .into_iter()
+                    .filter_map(|c| match c {
+                        ChildOfElement::Text(t) => Some(t.text()),
+                        _ => None,
+                    })
+                    .collect()
+            })
+    }
+
+    /// Parse `v42` or `n42` into `42`.
+    fn graphml_id(id: Option<&str>) -> Result<usize> {
+        static ID: LazyLock<Regex> = LazyLock::new(|| Regex::new("^[A-Za-z_]*([0-9]+)$").unwrap());
+        let id = id.with_context(|| "The identifier is absent")?;
+        let caps = ID
+            .captures(id)
+            .with_context(|| format!("Can't parse identifier '{id}'"))?;
+        Ok(usize::from_str(&caps[1])?)
+    }
+}
+
+#[test]
+fn prints_and_parses_graphml() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.put(0, &Hex::from_str_bytes("hello"));
+    g.add(1);
+    g.add(5);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(0, 5, Label::Alpha(3));
+    g.bind(5, 1, Label::from_str("a<b").unwrap());
+    let xml = g.to_graphml().unwrap();
+    let back: Sodg<16> = Sodg::from_graphml(&xml, 256).unwrap();
+    assert_eq!(3, back.len());
+    assert_eq!(Some(1), back.kid(0, Label::from_str("foo").unwrap()));
+    assert_eq!(Some(5), back.kid(0, Label::Alpha(3)));
+    assert_eq!(Some(1), back.kid(5, Label::from_str("a<b").unwrap()));
+    assert_eq!(b"hello".to_vec(), back.peek(0).unwrap().to_vec());
+    assert!(back.peek(1).is_none());
+}
+
+#[test]
+fn rejects_broken_graphml() {
+    assert!(Sodg::<16>::from_graphml("<graphml/>", 16).is_err());
+    assert!(
+        Sodg::<16>::from_graphml("<graphml><graph><node id='x'/></graph></graphml>", 16).is_err()
+    );
+}
+
+#[test]
+fn rejects_edges_to_undeclared_nodes() {
+    let xml = |target: &str| {
+        format!(
+            "<graphml><graph edgedefault=\"directed\">\
+            <node id=\"v0\"/><node id=\"v1\"/>\
+            <edge source=\"v0\" target=\"{target}\"><data key=\"label\">foo</data></edge>\
+            </graph></graphml>"
+        )
+    };
+    let ok: Sodg<16> = Sodg::from_graphml(&xml("v1"), 16).unwrap();
+    assert_eq!(Some(1), ok.kid(0, Label::from_str("foo").unwrap()));
+    assert!(Sodg::<16>::from_graphml(&xml("v7"), 16).is_err());
+    assert!(Sodg::<16>::from_graphml(&xml("v100000000"), 16).is_err());
+}
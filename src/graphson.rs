@@ -0,0 +1,206 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Persistence, Sodg};
+use anyhow::{anyhow, Context, Result};
+use itertools::Itertools;
+use serde_json::{json, Value};
+use std::str::FromStr;
+
+impl<const N: usize> Sodg<N> {
+    /// Print the graph in [GraphSON v3](https://tinkerpop.apache.org/docs/current/dev/io/#graphson-3d0)
+    /// format, which is understood by Apache `TinkerPop` and Gremlin-compatible
+    /// databases, like `JanusGraph` or Amazon Neptune.
+    ///
+    /// Vertices are printed with the `vertex` label, their data (if present) go
+    /// into the `data` property as a hexadecimal string. Edges are printed with
+    /// their labels. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// g.put(1, &Hex::from_str_bytes("hi"));
+    /// let json = g.to_graphson_v3();
+    /// assert!(json.starts_with("{\"@type\":\"tinker:graph\""));
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic if vertices are absent (should never happen, though).
+    #[must_use]
+    pub fn to_graphson_v3(&self) -> String {
+        let mut vertices = vec![];
+        let mut edges = vec![];
+        for v in self.keys() {
+            let vtx = self.vertices.get(v).unwrap();
+            let mut value = json!({
+                "id": Self::int64(v),
+                "label": "vertex",
+            });
+            if vtx.persistence != Persistence::Empty {
+                value["properties"] = json!({
+                    "data": [{
+                        "@type": "g:VertexProperty",
+                        "@value": {
+                            "id": Self::int64(v),
+                            "value": vtx.data.print(),
+                            "label": "data",
+                        }
+                    }]
+                });
+            }
+            vertices.push(json!({"@type": "g:Vertex", "@value": value}));
+            for (a, to) in vtx.edges.iter().sorted() {
+                edges.push(json!({
+                    "@type": "g:Edge",
+                    "@value": {
+                        "id": Self::int64(edges.len()),
+                        "label": a.to_string(),
+                        "inVLabel": "vertex",
+                        "outVLabel": "vertex",
+                        "inV": Self::int64(*to),
+                        "outV": Self::int64(v),
+                    }
+                }));
+            }
+        }
+        json!({
+            "@type": "tinker:graph",
+            "@value": {"vertices": vertices, "edges": edges}
+        })
+        .to_string()
+    }
+
+    /// Make a new [`Sodg`] from a [GraphSON v3](https://tinkerpop.apache.org/docs/current/dev/io/#graphson-3d0)
+    /// document, as it is printed by [`Sodg::to_graphson_v3`].
+    ///
+    /// The `cap` is the capacity of the graph, as in [`Sodg::empty`].
+    ///
+    /// # Errors
+    ///
+    /// If the document is not valid JSON, doesn't look like a `GraphSON`
+    /// graph, or an ID of a vertex doesn't fit into the `cap`,
+    /// an error will be returned.
+    pub fn from_graphson_v3(s: &str, cap: usize) -> Result<Self> {
+        let doc: Value = serde_json::from_str(s).context("Can't parse GraphSON as JSON")?;
+        let graph = &doc["@value"];
+        let mut g = Self::empty(cap);
+        for vertex in Self::json_array(&graph["vertices"], "vertices")? {
+            let v = Self::from_int64(&vertex["@value"]["id"])?;
+            g.try_add_if_absent(v)?;
+            if let Some(props) = vertex["@value"]["properties"]["data"].as_array() {
+                for p in props {
+                    let hex = p["@value"]["value"]
+                        .as_str()
+                        .with_context(|| format!("The data of ν{v} is not a string"))?;
+                    g.put(v, &Hex::from_str(hex)?);
+                }
+            }
+        }
+        for edge in Self::json_array(&graph["edges"], "edges")? {
+            let value = &edge["@value"];
+            let from = Self::from_int64(&value["outV"])?;
+            let to = Self::from_int64(&value["inV"])?;
+            let a = value["label"]
+                .as_str()
+                .with_context(|| format!("The label of the edge from ν{from} is not a string"))?;
+            g.try_add_if_absent(from)?;
+            g.try_add_if_absent(to)?;
+            g.bind(from, to, Label::from_str(a)?);
+        }
+        Ok(g)
+    }
+
+    /// Make a typed `GraphSON` integer.
+    fn int64(v: usize) -> Value {
+        json!({"@type": "g:Int64", "@value": v})
+    }
+
+    /// Read a typed `GraphSON` integer.
+    fn from_int64(v: &Value) -> Result<usize> {
+        let n = v["@value"]
+            .as_u64()
+            .or_else(|| v.as_u64())
+            .with_context(|| format!("Can't read an ID from {v}"))?;
+        usize::try_from(n).map_err(|e| anyhow!("The ID {n} is too big: {e}"))
+    }
+
+    /// Read an array from `GraphSON`, which may be absent.
+    fn json_array<'a>(v: &'a Value, name: &str) -> Result<&'a Vec<Value>> {
+        static EMPTY: Vec<Value> = vec![];
+        if v.is_null() {
+            return Ok(&EMPTY);
+        }
+        v.as_array()
+            .with_context(|| format!("The '{name}' in GraphSON is not an array"))
+    }
+}
+
+#[test]
+fn prints_graphson() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.put(1, &Hex::from_str_bytes("hi"));
+    let doc: Value = serde_json::from_str(&g.to_graphson_v3()).unwrap();
+    let graph = &doc["@value"];
+    assert_eq!(2, graph["vertices"].as_array().unwrap().len());
+    assert_eq!("foo", graph["edges"][0]["@value"]["label"]);
+    assert_eq!(1, graph["edges"][0]["@value"]["inV"]["@value"]);
+    assert_eq!(
+        "68-69",
+        graph["vertices"][1]["@value"]["properties"]["data"][0]["@value"]["value"]
+    );
+}
+
+#[test]
+fn reads_graphson_back() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(1, 2, Label::Alpha(3));
+    g.put(2, &Hex::from(42));
+    let after: Sodg<16> = Sodg::from_graphson_v3(&g.to_graphson_v3(), 256).unwrap();
+    after.assert_structurally_equal(&g);
+}
+
+#[test]
+fn rejects_broken_graphson() {
+    assert!(Sodg::<16>::from_graphson_v3("{\"@value\": {\"vertices\": 5}}", 256).is_err());
+    assert!(Sodg::<16>::from_graphson_v3("not json", 256).is_err());
+}
+
+#[test]
+fn rejects_too_big_vertex() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(100);
+    g.bind(0, 100, Label::Alpha(0));
+    assert!(Sodg::<16>::from_graphson_v3(&g.to_graphson_v3(), 16).is_err());
+    let edge = "{\"@value\": {\"vertices\": [], \"edges\": [{\"@value\": {\"outV\": {\"@type\": \"g:Int64\", \"@value\": 0}, \"inV\": {\"@type\": \"g:Int64\", \"@value\": 100000000}, \"label\": \"α0\"}}]}}";
+    assert!(Sodg::<16>::from_graphson_v3(edge, 16).is_err());
+}
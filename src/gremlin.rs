@@ -0,0 +1,137 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{GremlinTraversal, Hex, Label, Sodg};
+
+impl<const N: usize> Sodg<N> {
+    /// Start a Gremlin-style traversal at the vertex `start`.
+    #[must_use]
+    pub fn gremlin_traversal(&self, start: usize) -> GremlinTraversal<'_, N> {
+        GremlinTraversal {
+            g: self,
+            current: vec![start],
+        }
+    }
+}
+
+impl<const N: usize> GremlinTraversal<'_, N> {
+    /// Move along the outgoing edges labeled `a`.
+    #[must_use]
+    pub fn out(self, a: Label) -> Self {
+        let next = self
+            .current
+            .iter()
+            .filter_map(|v| self.g.kid(*v, a))
+            .collect();
+        Self {
+            g: self.g,
+            current: next,
+        }
+    }
+
+    /// Move backward along the incoming edges labeled `a`.
+    ///
+    /// The name has a trailing underscore, because `in` is a keyword in Rust.
+    #[must_use]
+    pub fn in_(self, a: Label) -> Self {
+        let mut next = vec![];
+        for v in &self.current {
+            for u in self.g.keys() {
+                if self.g.kid(u, a) == Some(*v) {
+                    next.push(u);
+                }
+            }
+        }
+        Self {
+            g: self.g,
+            current: next,
+        }
+    }
+
+    /// Keep only the vertices that have data.
+    #[must_use]
+    pub fn has_data(self) -> Self {
+        let next = self
+            .current
+            .into_iter()
+            .filter(|v| self.g.peek(*v).is_some())
+            .collect();
+        Self {
+            g: self.g,
+            current: next,
+        }
+    }
+
+    /// Take the data of the vertices, skipping the ones without data.
+    ///
+    /// The data is not "taken" from the graph, as [`Sodg::data`] does, which
+    /// means that the vertices are not submitted to garbage collection.
+    #[must_use]
+    pub fn values(&self) -> Vec<Hex> {
+        self.current
+            .iter()
+            .filter_map(|v| self.g.peek(*v))
+            .cloned()
+            .collect()
+    }
+
+    /// Count the vertices.
+    #[must_use]
+    pub const fn count(&self) -> usize {
+        self.current.len()
+    }
+
+    /// Take the vertices.
+    #[must_use]
+    pub fn to_list(&self) -> Vec<usize> {
+        self.current.clone()
+    }
+}
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn walks_forward_and_backward() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    let item = Label::from_str("item").unwrap();
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, item);
+    g.bind(2, 3, item);
+    g.put(3, &Hex::from(42));
+    let t = g.gremlin_traversal(3).in_(item);
+    assert_eq!(vec![1, 2], t.to_list());
+    let back = t.out(item).has_data();
+    assert_eq!(2, back.count());
+    assert_eq!(vec![Hex::from(42), Hex::from(42)], back.values());
+}
+
+#[test]
+fn walks_nowhere() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    let t = g.gremlin_traversal(0).out(Label::Alpha(0));
+    assert_eq!(0, t.count());
+    assert!(t.values().is_empty());
+}
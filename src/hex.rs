@@ -98,7 +98,7 @@ impl Hex {
     /// assert_eq!(8, d.len());
     /// ```
     #[must_use]
-    pub fn len(&self) -> usize {
+    pub const fn len(&self) -> usize {
         match self {
             Self::Vector(x) => x.len(),
             Self::Bytes(_, size) => *size,
@@ -174,7 +174,7 @@ impl Hex {
     /// assert_eq!(true, d.is_empty());
     /// ```
     #[must_use]
-    pub fn is_empty(&self) -> bool {
+    pub const fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
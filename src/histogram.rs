@@ -0,0 +1,79 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Count how many times each label is used by the edges
+    /// of the graph. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// g.bind(1, 0, Label::from_str("foo").unwrap());
+    /// assert_eq!(2, g.edge_label_histogram()["foo"]);
+    /// ```
+    #[must_use]
+    pub fn edge_label_histogram(&self) -> HashMap<String, usize> {
+        let mut histogram = HashMap::new();
+        for v in self.keys() {
+            for (a, _) in self.kids(v) {
+                *histogram.entry(a.to_string()).or_default() += 1;
+            }
+        }
+        histogram
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn counts_labels() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(1, 2, Label::from_str("foo").unwrap());
+    g.bind(2, 3, Label::from_str("foo").unwrap());
+    g.bind(0, 2, Label::from_str("bar").unwrap());
+    g.bind(3, 0, Label::from_str("bar").unwrap());
+    assert_eq!(
+        HashMap::from([("foo".to_string(), 3), ("bar".to_string(), 2)]),
+        g.edge_label_histogram()
+    );
+    g.erase(3);
+    assert_eq!(1, g.edge_label_histogram()["bar"]);
+}
+
+#[test]
+fn counts_nothing_in_empty_graph() {
+    let g: Sodg<16> = Sodg::empty(256);
+    assert!(g.edge_label_histogram().is_empty());
+}
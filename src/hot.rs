@@ -0,0 +1,132 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, VecDeque};
+
+impl<const N: usize> Sodg<N> {
+    /// Find the path, which is taken most frequently by the observed
+    /// traversals.
+    ///
+    /// Each traversal is a pair of `(start, end)` vertices, which is turned
+    /// into the shortest path between them. The path that appears most
+    /// frequently is returned, as a sequence of vertices, from start to end.
+    /// If a few paths are equally frequent, the one observed first wins.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// let hot = g.hot_path(&[(0, 1), (0, 2), (0, 2)]).unwrap();
+    /// assert_eq!(vec![0, 1, 2], hot);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the list of traversals is empty or there is no path between
+    /// some start and end, an error will be returned.
+    pub fn hot_path(&self, traversals: &[(usize, usize)]) -> Result<Vec<usize>> {
+        let mut counts: HashMap<Vec<usize>, usize> = HashMap::new();
+        let mut paths = vec![];
+        for (start, end) in traversals {
+            let path = self
+                .shortest_path(*start, *end)
+                .ok_or_else(|| anyhow!("There is no path from ν{start} to ν{end}"))?;
+            let c = counts.entry(path.clone()).or_default();
+            if *c == 0 {
+                paths.push(path);
+            }
+            *c += 1;
+        }
+        let mut hot: Option<&Vec<usize>> = None;
+        for p in &paths {
+            if hot.is_none_or(|h| counts[p] > counts[h]) {
+                hot = Some(p);
+            }
+        }
+        hot.cloned()
+            .ok_or_else(|| anyhow!("There are no traversals"))
+    }
+
+    /// Find the shortest path between two vertices, including both of them.
+    ///
+    /// Kids are visited in the order of their labels, that's why the result
+    /// is always the same for the same graph.
+    pub(crate) fn shortest_path(&self, start: usize, end: usize) -> Option<Vec<usize>> {
+        if !self.alive(start) || !self.alive(end) {
+            return None;
+        }
+        let mut parents: HashMap<usize, usize> = HashMap::from([(start, start)]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(v) = queue.pop_front() {
+            if v == end {
+                let mut path = vec![end];
+                let mut p = end;
+                while p != start {
+                    p = parents[&p];
+                    path.push(p);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            let mut kids: Vec<_> = self.kids(v).collect();
+            kids.sort();
+            for (_, to) in kids {
+                if !parents.contains_key(to) {
+                    parents.insert(*to, v);
+                    queue.push_back(*to);
+                }
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn finds_most_frequent_path() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.bind(3, 4, Label::Alpha(0));
+    let hot = g.hot_path(&[(0, 2), (0, 4), (3, 4), (0, 4)]).unwrap();
+    assert_eq!(vec![0, 3, 4], hot);
+}
+
+#[test]
+fn fails_without_path() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    assert!(g.hot_path(&[(0, 1)]).is_err());
+    assert!(g.hot_path(&[]).is_err());
+}
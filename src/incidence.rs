@@ -0,0 +1,121 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Make an incidence matrix of the graph.
+    ///
+    /// The function returns IDs of vertices (sorted), IDs of edges (virtual,
+    /// just numbers from zero, in the order of departure vertices and then
+    /// labels), and the matrix itself, where `matrix[i][j]` is `-1` if the
+    /// edge `j` departs from the vertex `i`, `1` if it arrives to it,
+    /// and `0` otherwise (also for loops). Edges to vertices that are
+    /// not alive anymore are skipped. The matrix is dense, its size
+    /// is `V×E`, so it's suitable only for small graphs. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let (vertices, edges, m) = g.to_incidence_matrix();
+    /// assert_eq!(vec![0, 1], vertices);
+    /// assert_eq!(vec![0], edges);
+    /// assert_eq!(vec![vec![-1], vec![1]], m);
+    /// ```
+    #[must_use]
+    pub fn to_incidence_matrix(&self) -> (Vec<usize>, Vec<usize>, Vec<Vec<i8>>) {
+        let mut vertices = self.keys();
+        vertices.sort_unstable();
+        let rows: HashMap<usize, usize> =
+            vertices.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut pairs = vec![];
+        for v in &vertices {
+            for (_, to) in self.kids(*v) {
+                if rows.contains_key(to) {
+                    pairs.push((*v, *to));
+                }
+            }
+        }
+        let mut matrix = vec![vec![0; pairs.len()]; vertices.len()];
+        for (j, (from, to)) in pairs.iter().enumerate() {
+            if from != to {
+                matrix[rows[from]][j] = -1;
+                matrix[rows[to]][j] = 1;
+            }
+        }
+        (vertices, (0..pairs.len()).collect(), matrix)
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn makes_matrix_of_path() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    let (vertices, edges, m) = g.to_incidence_matrix();
+    assert_eq!(vec![0, 1, 2], vertices);
+    assert_eq!(vec![0, 1], edges);
+    assert_eq!(vec![vec![-1, 0], vec![1, -1], vec![0, 1]], m);
+}
+
+#[test]
+fn sums_columns_to_zero() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(3, 3, Label::Alpha(0));
+    g.bind(3, 0, Label::Alpha(1));
+    let (_, edges, m) = g.to_incidence_matrix();
+    for j in edges {
+        assert_eq!(0, m.iter().map(|row| row[j]).sum::<i8>());
+    }
+}
+
+#[test]
+fn skips_edges_to_collected_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let (vertices, edges, m) = g.to_incidence_matrix();
+    assert_eq!(g.len(), vertices.len());
+    for j in edges {
+        assert_eq!(0, m.iter().map(|row| row[j]).sum::<i8>());
+    }
+}
@@ -0,0 +1,173 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Context, Result};
+use bincode::{deserialize_from, serialize};
+#[cfg(debug_assertions)]
+use log::trace;
+use serde::{Deserialize, Serialize};
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+#[cfg(debug_assertions)]
+use std::time::Instant;
+
+/// The size of one entry in the `.idx` file: vertex ID and offset.
+const ENTRY: u64 = 16;
+
+/// Edges of a vertex: labels and targets.
+type Edges = Vec<(String, usize)>;
+
+/// A vertex, as it is stored in the `.data` file.
+#[derive(Serialize, Deserialize)]
+struct Record {
+    data: Option<Vec<u8>>,
+    edges: Edges,
+}
+
+impl<const N: usize> Sodg<N> {
+    /// Save the graph into two files: `{base}.data` with all vertices,
+    /// one after another, and `{base}.idx` with a sorted list of
+    /// `(vertex, offset)` pairs, which point to the vertices in
+    /// the `.data` file.
+    ///
+    /// Then, a single vertex may be read back by
+    /// [`Sodg::vertex_from_binary_indexed`], without loading the entire graph.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to save, an error will be returned.
+    pub fn to_binary_indexed(&self, base: impl AsRef<Path>) -> Result<()> {
+        #[cfg(debug_assertions)]
+        let start = Instant::now();
+        let base = base.as_ref();
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut data = vec![];
+        let mut idx = Vec::with_capacity(keys.len() * 16);
+        for v in &keys {
+            idx.extend_from_slice(&(*v as u64).to_be_bytes());
+            idx.extend_from_slice(&(data.len() as u64).to_be_bytes());
+            let mut edges: Edges = self.kids(*v).map(|(a, to)| (a.to_string(), *to)).collect();
+            edges.sort();
+            let record = Record {
+                data: self.peek(*v).map(crate::Hex::to_vec),
+                edges,
+            };
+            data.extend(serialize(&record).with_context(|| format!("Can't serialize ν{v}"))?);
+        }
+        let file = Self::with_ext(base, ".data");
+        fs::write(&file, &data).with_context(|| format!("Can't write to {}", file.display()))?;
+        let file = Self::with_ext(base, ".idx");
+        fs::write(&file, &idx).with_context(|| format!("Can't write to {}", file.display()))?;
+        #[cfg(debug_assertions)]
+        trace!(
+            "Saved {} vertices ({} bytes) to {}.data/.idx in {:?}",
+            keys.len(),
+            data.len(),
+            base.display(),
+            start.elapsed()
+        );
+        Ok(())
+    }
+
+    /// Read one vertex from the files created by [`Sodg::to_binary_indexed`].
+    ///
+    /// The index is searched with a binary search, and then only the
+    /// vertex itself is read from the `.data` file. The function returns
+    /// the data of the vertex (if it has any) and its edges, sorted
+    /// by their labels.
+    ///
+    /// # Errors
+    ///
+    /// If the files can't be read or the vertex is not there,
+    /// an error will be returned.
+    pub fn vertex_from_binary_indexed(base: &Path, v: usize) -> Result<(Option<Vec<u8>>, Edges)> {
+        let file = Self::with_ext(base, ".idx");
+        let mut idx =
+            File::open(&file).with_context(|| format!("Can't open {}", file.display()))?;
+        let total = idx.metadata()?.len() / ENTRY;
+        let (mut lo, mut hi) = (0, total);
+        let mut offset = None;
+        let mut entry = [0u8; 16];
+        while lo < hi {
+            let mid = u64::midpoint(lo, hi);
+            idx.seek(SeekFrom::Start(mid * ENTRY))?;
+            idx.read_exact(&mut entry)
+                .with_context(|| format!("Can't read entry #{mid} of {}", file.display()))?;
+            let id = u64::from_be_bytes(entry[..8].try_into()?);
+            match id.cmp(&(v as u64)) {
+                std::cmp::Ordering::Less => lo = mid + 1,
+                std::cmp::Ordering::Greater => hi = mid,
+                std::cmp::Ordering::Equal => {
+                    offset = Some(u64::from_be_bytes(entry[8..].try_into()?));
+                    break;
+                }
+            }
+        }
+        let offset = offset.ok_or_else(|| anyhow!("Can't find ν{v} in {}", file.display()))?;
+        let file = Self::with_ext(base, ".data");
+        let mut data =
+            File::open(&file).with_context(|| format!("Can't open {}", file.display()))?;
+        data.seek(SeekFrom::Start(offset))?;
+        let record: Record = deserialize_from(data)
+            .with_context(|| format!("Can't read ν{v} from {}", file.display()))?;
+        Ok((record.data, record.edges))
+    }
+
+    /// Add an extension to the base path.
+    fn with_ext(base: &Path, ext: &str) -> PathBuf {
+        let mut p = OsString::from(base.as_os_str());
+        p.push(ext);
+        PathBuf::from(p)
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+use tempfile::TempDir;
+
+#[test]
+fn reads_single_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in [0, 3, 7, 42] {
+        g.add(v);
+    }
+    g.bind(0, 42, Label::Alpha(1));
+    g.bind(0, 7, Label::Alpha(0));
+    g.put(7, &Hex::from_str_bytes("hello"));
+    let tmp = TempDir::new().unwrap();
+    let base = tmp.path().join("graph");
+    g.to_binary_indexed(&base).unwrap();
+    assert!(tmp.path().join("graph.data").exists());
+    assert!(tmp.path().join("graph.idx").exists());
+    let (data, edges) = Sodg::<16>::vertex_from_binary_indexed(&base, 0).unwrap();
+    assert!(data.is_none());
+    assert_eq!(vec![("α0".to_string(), 7), ("α1".to_string(), 42)], edges);
+    let (data, edges) = Sodg::<16>::vertex_from_binary_indexed(&base, 7).unwrap();
+    assert_eq!(b"hello".to_vec(), data.unwrap());
+    assert!(edges.is_empty());
+    assert!(Sodg::<16>::vertex_from_binary_indexed(&base, 3).is_ok());
+    assert!(Sodg::<16>::vertex_from_binary_indexed(&base, 5).is_err());
+}
@@ -0,0 +1,273 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use serde_json::{json, Map, Value};
+use std::collections::HashSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Infer a [JSON Schema](https://json-schema.org/) from the structure
+    /// of the graph, starting from the vertex `root`.
+    ///
+    /// A vertex with kids becomes an `object` with edge labels as its
+    /// `properties`. A vertex with eight bytes of data becomes an
+    /// `integer`, while a vertex with any other data becomes a `string`
+    /// (hexadecimal). A vertex without data and without kids, as well as
+    /// an edge that leads back to the `root`, becomes an empty schema `{}`.
+    /// A vertex reachable by more than one edge is described only once,
+    /// under `$defs`, and all edges point to it with `$ref`, thus the
+    /// schema stays linear in size even for graphs full of diamonds.
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("age").unwrap());
+    /// g.put(1, &Hex::from(42));
+    /// let schema = g.to_json_schema(0);
+    /// assert_eq!("integer", schema["properties"]["age"]["type"]);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If the `root` vertex is absent, it will panic.
+    #[must_use]
+    pub fn to_json_schema(&self, root: usize) -> Value {
+        let mut shared = HashSet::new();
+        let mut seen = HashSet::from([root]);
+        let mut todo = vec![root];
+        while let Some(v) = todo.pop() {
+            for (_, to) in self.live_kids(v) {
+                if seen.insert(*to) {
+                    todo.push(*to);
+                } else if *to != root {
+                    shared.insert(*to);
+                }
+            }
+        }
+        let mut defs = Map::new();
+        let mut schema = self.json_schema_of(root, root, &shared, &mut defs);
+        if !defs.is_empty() {
+            schema["$defs"] = Value::Object(defs);
+        }
+        schema["$schema"] = json!("https://json-schema.org/draft/2020-12/schema");
+        schema
+    }
+
+    /// Make a JSON Schema of the vertex `v`, which the edge leads to,
+    /// or a `$ref` to its definition, if it is shared.
+    fn json_schema_at(
+        &self,
+        v: usize,
+        root: usize,
+        shared: &HashSet<usize>,
+        defs: &mut Map<String, Value>,
+    ) -> Value {
+        if v == root {
+            return json!({});
+        }
+        if !shared.contains(&v) {
+            return self.json_schema_of(v, root, shared, defs);
+        }
+        let name = format!("v{v}");
+        if !defs.contains_key(&name) {
+            defs.insert(name.clone(), json!({}));
+            let def = self.json_schema_of(v, root, shared, defs);
+            defs.insert(name.clone(), def);
+        }
+        json!({"$ref": format!("#/$defs/{name}")})
+    }
+
+    /// Make a JSON Schema of a single vertex, recursively.
+    fn json_schema_of(
+        &self,
+        v: usize,
+        root: usize,
+        shared: &HashSet<usize>,
+        defs: &mut Map<String, Value>,
+    ) -> Value {
+        let mut properties = Map::new();
+        for (a, to) in self.live_kids(v) {
+            properties.insert(a.to_string(), self.json_schema_at(*to, root, shared, defs));
+        }
+        if !properties.is_empty() {
+            let required: Vec<String> = properties.keys().cloned().collect();
+            return json!({
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            });
+        }
+        match self.peek(v) {
+            Some(d) if d.len() == 8 => json!({"type": "integer"}),
+            Some(_) => json!({"type": "string", "pattern": "^([0-9A-F]{2}(-[0-9A-F]{2})*)?$"}),
+            None => json!({}),
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn makes_nested_schema() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("user").unwrap());
+    g.bind(1, 2, Label::from_str("name").unwrap());
+    g.bind(1, 3, Label::from_str("age").unwrap());
+    g.put(2, &Hex::from_str_bytes("Jeff"));
+    g.put(3, &Hex::from(42));
+    let schema = g.to_json_schema(0);
+    assert_eq!("object", schema["type"]);
+    assert!(schema["$schema"].as_str().unwrap().starts_with("https://"));
+    let user = &schema["properties"]["user"];
+    assert_eq!("object", user["type"]);
+    assert!(user.get("$schema").is_none());
+    assert_eq!("string", user["properties"]["name"]["type"]);
+    assert_eq!("integer", user["properties"]["age"]["type"]);
+    assert_eq!(json!(["age", "name"]), user["required"]);
+}
+
+#[test]
+fn makes_empty_schema() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::from_str("x").unwrap());
+    g.bind(1, 0, Label::from_str("back").unwrap());
+    let schema = g.to_json_schema(0);
+    assert_eq!(json!({}), schema["properties"]["x"]["properties"]["back"]);
+    let mut lone: Sodg<16> = Sodg::empty(256);
+    lone.add(0);
+    assert_eq!(1, lone.to_json_schema(0).as_object().unwrap().len());
+}
+
+#[cfg(test)]
+fn conforms(doc: &Value, schema: &Value, root: &Value) -> bool {
+    let schema = schema["$ref"]
+        .as_str()
+        .map_or(schema, |r| &root["$defs"][r.trim_start_matches("#/$defs/")]);
+    let matches = match schema["type"].as_str() {
+        Some("object") => doc.is_object(),
+        Some("integer") => doc.is_i64(),
+        Some("string") => doc.as_str().is_some_and(|s| {
+            schema["pattern"]
+                .as_str()
+                .is_none_or(|p| regex::Regex::new(p).unwrap().is_match(s))
+        }),
+        _ => true,
+    };
+    matches
+        && schema["required"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .all(|k| doc.get(k.as_str().unwrap()).is_some())
+        && schema["properties"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .all(|(k, s)| doc.get(k).is_none_or(|d| conforms(d, s, root)))
+}
+
+#[test]
+fn validates_documents() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("name").unwrap());
+    g.bind(0, 2, Label::from_str("age").unwrap());
+    g.put(1, &Hex::from_str_bytes("Jeff"));
+    g.put(2, &Hex::from(42));
+    let schema = g.to_json_schema(0);
+    assert!(conforms(
+        &json!({"name": "4A-65-66-66", "age": 42}),
+        &schema,
+        &schema
+    ));
+    assert!(!conforms(
+        &json!({"name": "Jeff", "age": 42}),
+        &schema,
+        &schema
+    ));
+    assert!(!conforms(
+        &json!({"name": "4A", "age": "old"}),
+        &schema,
+        &schema
+    ));
+    assert!(!conforms(&json!({"age": 42}), &schema, &schema));
+}
+
+#[cfg(test)]
+fn diamonds(n: usize) -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..=3 * n {
+        g.add(v);
+    }
+    let label = |a: &str| Label::from_str(a).unwrap();
+    for i in 0..n {
+        let top = 3 * i;
+        g.bind(top, top + 1, label("a"));
+        g.bind(top, top + 1, label("c"));
+        g.bind(top, top + 2, label("b"));
+        g.bind(top, top + 2, label("d"));
+        for mid in [top + 1, top + 2] {
+            g.bind(mid, top + 3, label("x"));
+            g.bind(mid, top + 3, label("y"));
+        }
+    }
+    g.put(3 * n, &Hex::from(42));
+    g
+}
+
+#[test]
+fn describes_shared_vertices_once() {
+    let schema = diamonds(5).to_json_schema(0);
+    assert_eq!(15, schema["$defs"].as_object().unwrap().len());
+    assert!(schema.to_string().len() < 5 * 1000);
+    let one = diamonds(1).to_json_schema(0);
+    assert_eq!(json!({"$ref": "#/$defs/v2"}), one["properties"]["d"]);
+    assert_eq!(
+        json!({"$ref": "#/$defs/v3"}),
+        one["$defs"]["v2"]["properties"]["y"]
+    );
+    assert_eq!("integer", one["$defs"]["v3"]["type"]);
+    let leaf = json!({"x": 42, "y": 7});
+    assert!(conforms(
+        &json!({"a": leaf, "b": leaf, "c": leaf, "d": leaf}),
+        &one,
+        &one
+    ));
+    assert!(!conforms(
+        &json!({"a": leaf, "b": leaf, "c": leaf, "d": {"x": 42, "y": "7"}}),
+        &one,
+        &one
+    ));
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::{BTreeSet, HashMap};
+
+impl<const N: usize> Sodg<N> {
+    /// Make a copy of the k-core of the graph: the biggest subgraph, where
+    /// each vertex has at least `k` neighbors.
+    ///
+    /// Edges are treated as undirected, while loops and parallel edges
+    /// are ignored. Edges to vertices that are not in the k-core are dropped.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.add(3);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(2, 0, Label::Alpha(0));
+    /// g.bind(2, 3, Label::Alpha(1));
+    /// assert_eq!(3, g.k_core(2).len());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// May panic if the graph is broken (should never happen, though).
+    #[must_use]
+    pub fn k_core(&self, k: usize) -> Self {
+        let cores = self.core_number();
+        let mut g = self.clone();
+        for v in self.keys() {
+            if cores[&v] < k {
+                g.erase(v);
+            } else {
+                g.vertices
+                    .get_mut(v)
+                    .unwrap()
+                    .edges
+                    .retain(|_, to| cores.get(to).is_some_and(|c| *c >= k));
+                g.sort_kids(v);
+            }
+        }
+        g.reindex();
+        g.recount();
+        g
+    }
+
+    /// Calculate the core number of each vertex: the biggest `k`, such that
+    /// the vertex belongs to the k-core of the graph, see [`Sodg::k_core`].
+    #[must_use]
+    pub fn core_number(&self) -> HashMap<usize, usize> {
+        let adj = self.undirected();
+        let mut degrees: HashMap<usize, usize> = adj.iter().map(|(v, n)| (*v, n.len())).collect();
+        let mut queue: BTreeSet<(usize, usize)> = degrees.iter().map(|(v, d)| (*d, *v)).collect();
+        let mut cores = HashMap::new();
+        let mut k = 0;
+        while let Some((d, v)) = queue.pop_first() {
+            k = k.max(d);
+            cores.insert(v, k);
+            for u in &adj[&v] {
+                if cores.contains_key(u) {
+                    continue;
+                }
+                let du = degrees[u];
+                queue.remove(&(du, *u));
+                degrees.insert(*u, du - 1);
+                queue.insert((du - 1, *u));
+            }
+        }
+        cores
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+use crate::ctors::complete;
+
+#[test]
+fn keeps_complete_graph() {
+    let g = complete(5);
+    let core = g.k_core(4);
+    assert_eq!(5, core.len());
+    assert_eq!(4, core.kids(0).count());
+    assert!(g.k_core(5).is_empty());
+    assert!(g.core_number().values().all(|c| *c == 4));
+}
+
+#[test]
+fn peels_path_and_isolated_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    for v in 0..3 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    assert!(g.k_core(2).is_empty());
+    assert_eq!(5, g.k_core(0).len());
+    let core = g.k_core(1);
+    assert_eq!(4, core.len());
+    assert_eq!(Some(1), core.kid(0, Label::Alpha(0)));
+}
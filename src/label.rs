@@ -20,6 +20,7 @@
 
 use crate::Label;
 use anyhow::anyhow;
+use std::cmp::Ordering;
 use std::fmt;
 use std::fmt::{Debug, Display, Formatter};
 use std::str::FromStr;
@@ -47,6 +48,45 @@ impl FromStr for Label {
     }
 }
 
+impl Label {
+    /// The text of a non-`Alpha` label, as a sequence of chars.
+    fn chars(&self) -> &[char] {
+        match self {
+            Self::Greek(c) => std::slice::from_ref(c),
+            Self::Alpha(_) => &[],
+            Self::Str(a) => {
+                let len = a.iter().rposition(|c| *c != ' ').map_or(0, |p| p + 1);
+                &a[..len]
+            }
+        }
+    }
+}
+
+impl Ord for Label {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Self::Alpha(x), Self::Alpha(y)) => x.cmp(y),
+            (Self::Alpha(_), _) => Ordering::Less,
+            (_, Self::Alpha(_)) => Ordering::Greater,
+            _ => self
+                .chars()
+                .cmp(other.chars())
+                .then_with(|| match (self, other) {
+                    (Self::Str(x), Self::Str(y)) => x.cmp(y),
+                    (Self::Greek(_), Self::Str(_)) => Ordering::Less,
+                    (Self::Str(_), Self::Greek(_)) => Ordering::Greater,
+                    _ => Ordering::Equal,
+                }),
+        }
+    }
+}
+
+impl PartialOrd for Label {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Display for Label {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         <&Self as Debug>::fmt(&self, f)
@@ -75,3 +115,23 @@ fn parses_and_prints(#[case] txt: &str) {
     let l = Label::from_str(txt).unwrap();
     assert_eq!(txt, l.to_string());
 }
+
+#[test]
+fn orders_labels() {
+    let mut labels = [
+        Label::from_str("b").unwrap(),
+        Label::Alpha(10),
+        Label::from_str("ab").unwrap(),
+        Label::Alpha(2),
+        Label::from_str("abc").unwrap(),
+    ];
+    labels.sort();
+    assert_eq!(
+        "α2,α10,ab,abc,b",
+        labels
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+}
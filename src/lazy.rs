@@ -0,0 +1,230 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, LazySodg, Sodg, MAX_BRANCH_SIZE};
+use anyhow::{anyhow, Context, Result};
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+impl<const N: usize> Sodg<N> {
+    /// Make a graph that is built on demand, starting from the `root`.
+    ///
+    /// The `expander` returns the kids of a vertex, as triples of their
+    /// IDs, labels of the edges, and data (if any). It is called at most
+    /// once per vertex. The `cap` is the capacity of the graph, as in
+    /// [`Sodg::empty`].
+    ///
+    /// All materialized vertices are connected to the `root`, and
+    /// there may be no more than 16 vertices in one connected component,
+    /// so no more than 16 vertices may ever be materialized; beyond that,
+    /// [`LazySodg::force`] returns an error. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let lazy = Sodg::<16>::lazy(0, |v| vec![(v + 1, "next".to_string(), None)], 256);
+    /// assert_eq!(1, lazy.len());
+    /// assert_eq!(Some(1), lazy.kid(0, Label::from_str("next").unwrap()).unwrap());
+    /// assert_eq!(2, lazy.len());
+    /// ```
+    pub fn lazy<F>(root: usize, expander: F, cap: usize) -> LazySodg<N, F>
+    where
+        F: Fn(usize) -> Vec<(usize, String, Option<Vec<u8>>)>,
+    {
+        let mut g = Self::empty(cap);
+        g.add(root);
+        LazySodg {
+            root,
+            g: RefCell::new(g),
+            expanded: RefCell::new(HashSet::new()),
+            expander,
+        }
+    }
+}
+
+impl<const N: usize, F> LazySodg<N, F>
+where
+    F: Fn(usize) -> Vec<(usize, String, Option<Vec<u8>>)>,
+{
+    /// Materialize the kids of the vertex `v`, unless
+    /// they are already materialized.
+    ///
+    /// # Errors
+    ///
+    /// If the vertex `v` is not materialized yet, the expander returns
+    /// a label that can't be parsed or a kid that doesn't fit into the
+    /// capacity, or there is no room for the kids in the graph (no more than
+    /// 16 vertices, and no more than `N` edges of a vertex), an error will
+    /// be returned. The graph is not modified in this case.
+    pub fn force(&self, v: usize) -> Result<()> {
+        if self.expanded.borrow().contains(&v) {
+            return Ok(());
+        }
+        let mut g = self.g.borrow_mut();
+        if !g.alive(v) {
+            return Err(anyhow!("Vertex ν{v} is not materialized yet"));
+        }
+        let cap = g.vertices.capacity();
+        let mut kids = vec![];
+        let mut fresh = HashSet::new();
+        for (kid, label, data) in (self.expander)(v) {
+            if kid >= cap {
+                return Err(anyhow!(
+                    "The kid ν{kid} of ν{v} doesn't fit into {cap} vertices"
+                ));
+            }
+            let a = Label::from_str(&label)
+                .with_context(|| format!("Can't parse the label of ν{v}.{label}"))?;
+            if !g.alive(kid) {
+                fresh.insert(kid);
+            }
+            kids.push((kid, a, data));
+        }
+        if g.len() + fresh.len() > MAX_BRANCH_SIZE {
+            return Err(anyhow!(
+                "Can't materialize {} kids of ν{v}, since there may be no more than {MAX_BRANCH_SIZE} vertices",
+                fresh.len()
+            ));
+        }
+        let labels: HashSet<Label> = g
+            .kids(v)
+            .map(|(a, _)| *a)
+            .chain(kids.iter().map(|(_, a, _)| *a))
+            .collect();
+        if labels.len() > N {
+            return Err(anyhow!(
+                "Can't materialize kids of ν{v}, since a vertex may have no more than {N} edges"
+            ));
+        }
+        for (kid, a, data) in kids {
+            g.add_if_absent(kid);
+            if let Some(d) = data {
+                g.put(kid, &Hex::from_vec(d));
+            }
+            g.bind(v, kid, a);
+        }
+        self.expanded.borrow_mut().insert(v);
+        Ok(())
+    }
+
+    /// Find a kid of the vertex `v` by the label of the edge,
+    /// materializing the kids of `v` first.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to force the vertex `v`, an error will be returned.
+    pub fn kid(&self, v: usize, a: Label) -> Result<Option<usize>> {
+        self.force(v)?;
+        Ok(self.g.borrow().kid(v, a))
+    }
+
+    /// Get the total number of vertices materialized so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.g.borrow().len()
+    }
+
+    /// Is it empty (never, since the root is always there)?
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.g.borrow().is_empty()
+    }
+
+    /// Materialize all vertices not farther than `depth` hops from
+    /// the root and make a usual [`Sodg`] out of them.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to force some vertex, an error will be returned.
+    pub fn to_eager(&self, depth: usize) -> Result<Sodg<N>> {
+        let mut seen = HashSet::from([self.root]);
+        let mut wave = vec![self.root];
+        for _ in 0..depth {
+            let mut next = vec![];
+            for v in wave {
+                self.force(v)?;
+                for (_, to) in self.g.borrow().kids(v) {
+                    if seen.insert(*to) {
+                        next.push(*to);
+                    }
+                }
+            }
+            wave = next;
+        }
+        self.g.borrow().neighborhood_subgraph(self.root, depth)
+    }
+}
+
+#[cfg(test)]
+use std::cell::Cell;
+
+#[cfg(test)]
+fn binary(v: usize) -> Vec<(usize, String, Option<Vec<u8>>)> {
+    vec![
+        (2 * v + 1, "l".to_string(), Some(vec![1])),
+        (2 * v + 2, "r".to_string(), None),
+    ]
+}
+
+#[test]
+fn materializes_on_access() {
+    let calls = Cell::new(0);
+    let lazy = Sodg::<16>::lazy(
+        0,
+        |v| {
+            calls.set(calls.get() + 1);
+            binary(v)
+        },
+        256,
+    );
+    let l = Label::from_str("l").unwrap();
+    let left = lazy.kid(0, l).unwrap().unwrap();
+    assert_eq!(3, lazy.len());
+    assert_eq!(Some(3), lazy.kid(left, l).unwrap());
+    assert_eq!(5, lazy.len());
+    lazy.force(0).unwrap();
+    lazy.force(left).unwrap();
+    assert_eq!(5, lazy.len());
+    assert_eq!(2, calls.get());
+    assert!(lazy.force(42).is_err());
+}
+
+#[test]
+fn makes_eager_graph() {
+    let lazy = Sodg::<16>::lazy(0, binary, 256);
+    let mut g = lazy.to_eager(2).unwrap();
+    assert_eq!(7, g.len());
+    assert_eq!(vec![1], g.data(3).unwrap().to_vec());
+    assert_eq!(1, lazy.to_eager(0).unwrap().len());
+}
+
+#[test]
+fn refuses_to_overflow() {
+    let lazy = Sodg::<16>::lazy(0, binary, 256);
+    assert_eq!(15, lazy.to_eager(3).unwrap().len());
+    assert!(lazy.to_eager(4).is_err());
+    let endless = Sodg::<16>::lazy(0, |v| vec![(v + 1, "next".to_string(), None)], 256);
+    assert!(endless.to_eager(100).is_err());
+    assert_eq!(16, endless.len());
+    let wide = Sodg::<16>::lazy(0, |v| vec![(v + 1000, "far".to_string(), None)], 256);
+    assert!(wide.force(0).is_err());
+    assert_eq!(1, wide.len());
+}
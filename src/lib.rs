@@ -43,24 +43,86 @@
 #![allow(clippy::multiple_inherent_impl)]
 #![allow(clippy::multiple_crate_versions)]
 
+mod assert;
+mod attrs;
+mod autogc;
+mod bfs;
+mod centrality;
+mod clique;
 mod clone;
+mod components;
+mod contract;
+mod cow;
 mod ctors;
+mod cypher;
 mod debug;
+mod degree;
+mod diff;
+mod distances;
 mod dot;
+mod edges;
+mod embed;
+mod find;
+mod flatten;
+mod gc;
+#[cfg(feature = "gephi")]
+mod gephi;
+mod graphml;
+mod graphson;
+mod gremlin;
 mod hex;
+mod histogram;
+mod hot;
+mod incidence;
+mod indexed;
 mod inspect;
+mod jsonschema;
+mod kcore;
 mod label;
+mod lazy;
+mod logged;
+mod lookup;
 mod merge;
 mod misc;
+mod names;
+#[cfg(feature = "tokio")]
+mod ndjson;
 mod next;
 mod ops;
+mod pagerank;
+mod paths;
+mod prune;
+mod query;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rdf")]
+mod rdf;
+mod reachability;
+mod reduce;
+mod relation;
+mod roots;
+mod schema;
 mod script;
 mod serialization;
+mod similarity;
 mod slice;
+mod stream;
+mod threeway;
+mod topo;
+mod tracing;
+mod traversal;
+mod triangles;
+mod tx;
+mod validate;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+mod watch;
 mod xml;
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::any::Any;
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
 
 const HEX_SIZE: usize = 8;
 const MAX_BRANCHES: usize = 16;
@@ -91,7 +153,10 @@ pub enum Hex {
 }
 
 /// A label on an edge.
-#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Serialize, Deserialize)]
+///
+/// Labels are ordered: all `Alpha` labels go first, by their numbers,
+/// while the others follow, in lexicographic order of their texts.
+#[derive(PartialEq, Eq, Hash, Clone, Copy, Serialize, Deserialize)]
 pub enum Label {
     Greek(char),
     Alpha(usize),
@@ -117,6 +182,8 @@ pub struct Script {
     txt: String,
     /// The vars dynamically discovered.
     vars: HashMap<String, usize>,
+    /// Copies of the graph made by `CHECKPOINT` commands, by their names.
+    checkpoints: HashMap<String, Box<dyn Any>>,
 }
 
 /// A struct that represents a Surging Object Di-Graph (SODG).
@@ -143,9 +210,328 @@ pub struct Sodg<const N: usize> {
     stores: emap::Map<usize>,
     branches: emap::Map<microstack::Stack<usize, MAX_BRANCH_SIZE>>,
     vertices: emap::Map<Vertex<N>>,
+    /// Human-readable names of vertices, see [`Sodg::set_vertex_label`].
+    #[serde(skip_serializing, skip_deserializing)]
+    names: HashMap<usize, String>,
+    /// The inverse of the `names`.
+    #[serde(skip_serializing, skip_deserializing)]
+    named: HashMap<String, usize>,
+    /// Attributes of edges, see [`Sodg::set_edge_attr`].
+    #[serde(skip_serializing, skip_deserializing)]
+    attrs: HashMap<(usize, Label), HashMap<String, String>>,
     /// This is the next ID of a vertex to be returned by the [`Sodg::next_v`] function.
     #[serde(skip_serializing, skip_deserializing)]
     next_v: usize,
+    /// Callbacks registered by [`Sodg::watch_vertex`].
+    #[serde(skip_serializing, skip_deserializing)]
+    watchers: HashMap<usize, Vec<Watcher>>,
+    /// Is it counting the edges passed by [`Sodg::kid`]?
+    #[serde(skip_serializing, skip_deserializing)]
+    tracing: bool,
+    /// How many times each edge was passed, while tracing was on.
+    #[serde(skip_serializing, skip_deserializing)]
+    traces: RefCell<HashMap<(usize, Label), usize>>,
+    /// The index of all edges, if built by [`Sodg::build_index`].
+    #[serde(skip_serializing, skip_deserializing)]
+    index: Option<HashMap<(usize, Label), usize>>,
+    /// The numbers of edges pointing to vertices, if counted
+    /// by [`Sodg::with_autogc`].
+    #[serde(skip_serializing, skip_deserializing)]
+    refs: Option<HashMap<usize, usize>>,
+}
+
+/// A callback, which is notified about changes of a vertex.
+type Watcher = Box<dyn Fn(VertexEvent) + Send>;
+
+/// A change of a vertex, reported to a callback registered
+/// by [`Sodg::watch_vertex`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VertexEvent {
+    /// What happened.
+    pub kind: VertexEventKind,
+    /// The vertex at the other end of the edge, or the watched
+    /// vertex itself, if its data changed.
+    pub vertex: usize,
+}
+
+/// A kind of [`VertexEvent`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VertexEventKind {
+    DataChanged,
+    IncomingEdgeAdded,
+    IncomingEdgeRemoved,
+    OutgoingEdgeAdded,
+    OutgoingEdgeRemoved,
+}
+
+/// A mismatch between a graph and its schema, found
+/// by [`Sodg::verify_schema`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SchemaViolation {
+    /// The vertex doesn't have an edge required by the schema.
+    MissingEdge { from: usize, label: String },
+    /// The vertex doesn't have data, while the schema requires it.
+    MissingData { vertex: usize },
+}
+
+/// A Gremlin-style traversal over a [`Sodg`], which is made
+/// by [`Sodg::gremlin_traversal`].
+///
+/// The traversal keeps a list of vertices it currently stays at
+/// (duplicates are possible, as in Gremlin) and moves them along the edges:
+///
+/// ```
+/// use std::str::FromStr;
+/// use sodg::{Hex, Label, Sodg};
+/// let mut g : Sodg<16> = Sodg::empty(256);
+/// g.add(0);
+/// g.add(1);
+/// g.bind(0, 1, Label::from_str("foo").unwrap());
+/// g.put(1, &Hex::from(42));
+/// let t = g.gremlin_traversal(0).out(Label::from_str("foo").unwrap()).has_data();
+/// assert_eq!(vec![1], t.to_list());
+/// ```
+pub struct GremlinTraversal<'a, const N: usize> {
+    /// The graph.
+    g: &'a Sodg<N>,
+    /// The vertices we are at.
+    current: Vec<usize>,
+}
+
+/// A graph that is built on demand, which is made by [`Sodg::lazy`].
+///
+/// Kids of a vertex are materialized only when the vertex is forced,
+/// by calling the expander function.
+pub struct LazySodg<const N: usize, F> {
+    /// The vertex to start from.
+    root: usize,
+    /// The vertices materialized so far.
+    g: RefCell<Sodg<N>>,
+    /// The vertices already expanded.
+    expanded: RefCell<HashSet<usize>>,
+    /// The function that tells the kids of a vertex.
+    expander: F,
+}
+
+/// A copy-on-write [`Sodg`], which is made by [`Sodg::into_cow`].
+///
+/// Cloning it takes constant time, since clones share the same graph,
+/// until one of them gets mutated through [`CowSodg::graph_mut`],
+/// which makes a private copy of the graph for it.
+pub struct CowSodg<const N: usize> {
+    /// The graph, shared among clones.
+    g: std::rc::Rc<Sodg<N>>,
+}
+
+/// A [`Sodg`] that writes each change to an append-only log,
+/// which is made by [`Sodg::open_log`].
+///
+/// The graph can be restored from the log by [`Sodg::replay_log`].
+/// Vertices can be added, bound, unbound, filled with data, and deleted.
+pub struct LoggedSodg<const N: usize, W: std::io::Write> {
+    /// The graph.
+    g: Sodg<N>,
+    /// The log.
+    w: W,
+}
+
+/// A [`LoggedSodg`] that appends its changes to a file, which is
+/// made by [`Sodg::open_journal`].
+pub type Journal<const N: usize> = LoggedSodg<N, std::fs::File>;
+
+/// A transaction, which is made by [`Sodg::begin`].
+///
+/// It buffers changes of the graph, applying all of them at once
+/// by [`Tx::commit`] or none of them by [`Tx::rollback`].
+pub struct Tx<'a, const N: usize> {
+    /// The graph.
+    g: &'a mut Sodg<N>,
+    /// The changes, in the order they were made.
+    ops: Vec<TxOp>,
+}
+
+/// A decision about a conflict in [`Sodg::merge_with`], where both graphs
+/// have a kid with the same label.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeDecision {
+    /// Treat both kids as the same vertex, as [`Sodg::merge`] does.
+    Unify,
+    /// Attach the kid from the merged graph under another label.
+    Rename(Label),
+    /// Stop the merge with an error.
+    Reject,
+}
+
+/// Changes that [`Sodg::merge`] would make, as they are
+/// calculated by [`Sodg::merge_plan`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MergePlan {
+    /// Vertices to be added, in ascending order.
+    pub added: Vec<usize>,
+    /// Edges to be made or redirected, as departure vertices,
+    /// labels, and target vertices, in ascending order.
+    pub bound: Vec<(usize, Label, usize)>,
+    /// Vertices which data is to be set or changed, in ascending order.
+    pub overwritten: Vec<usize>,
+}
+
+/// A conflict found by [`Sodg::merge3`], which was left unresolved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MergeConflict {
+    /// Both sides changed the same edge differently.
+    Edge {
+        /// The vertex the edge departs from.
+        vertex: usize,
+        /// The label of the edge.
+        label: Label,
+        /// Where the edge points to in the common ancestor.
+        base: Option<usize>,
+        /// Where the edge points to in the current graph.
+        ours: Option<usize>,
+        /// Where the edge points to in the merged graph.
+        theirs: Option<usize>,
+    },
+    /// Both sides changed the data of the same vertex differently.
+    Data {
+        /// The vertex.
+        vertex: usize,
+    },
+}
+
+/// A single operation of a [`Patch`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum PatchOp {
+    /// Add a vertex.
+    Add(usize),
+    /// Make an edge from the first vertex to the second one.
+    Bind(usize, usize, Label),
+    /// Remove the edge with the label from the vertex.
+    Unbind(usize, Label),
+    /// Put data into a vertex.
+    Put(usize, Vec<u8>),
+    /// Delete a vertex together with all edges pointing to it.
+    Delete(usize),
+}
+
+/// A sequence of operations, which transforms one [`Sodg`] into
+/// another one, made by [`Sodg::diff`].
+///
+/// It can be serialized and sent over the network, to be applied by
+/// [`Patch::apply`] on the other side, or printed as a [`Script`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Patch {
+    /// The operations, in the order they must be applied.
+    ops: Vec<PatchOp>,
+}
+
+/// A precomputed transitive closure of a [`Sodg`], which is made by
+/// [`Sodg::precompute_reachability`].
+///
+/// It answers reachability queries in constant time, keeping one bit
+/// per pair of vertices.
+pub struct ReachabilityMatrix {
+    /// Positions of vertices in the matrix.
+    index: HashMap<usize, usize>,
+    /// The bits, row by row, where the row is the departure vertex.
+    bits: Vec<u8>,
+}
+
+/// A styler of the DOT graph printed by [`Sodg::to_dot_with`].
+///
+/// Each method returns DOT attributes, separated by commas, for
+/// a vertex or an edge. The defaults produce the same styling as
+/// [`Sodg::to_dot`], so only the methods that matter may be overridden:
+///
+/// ```
+/// use sodg::{DotStyler, Hex, Sodg};
+/// struct Boxes;
+/// impl DotStyler for Boxes {
+///     fn vertex(&self, v: usize, _data: Option<&Hex>) -> String {
+///         format!("shape=box,label=\"v{v}\"")
+///     }
+/// }
+/// let mut g : Sodg<16> = Sodg::empty(256);
+/// g.add(0);
+/// assert!(g.to_dot_with(&Boxes).contains("v0[shape=box,label=\"v0\"];"));
+/// ```
+pub trait DotStyler {
+    /// Attributes of the vertex `v`, which has the `data`, if any.
+    fn vertex(&self, v: usize, data: Option<&Hex>) -> String {
+        format!(
+            "shape=circle,label=\"ν{v}\"{}",
+            if data.is_some() {
+                ",color=\"#f96900\""
+            } else {
+                ""
+            }
+        )
+    }
+
+    /// Attributes of the edge from `from` to `to`, labeled as `a`.
+    fn edge(&self, from: usize, a: Label, to: usize) -> String {
+        let _ = (from, to);
+        format!(
+            "label=\"{a}\"{}",
+            match a {
+                Label::Greek('ρ' | 'σ') => ",color=gray,fontcolor=gray",
+                Label::Greek('π') => ",style=dashed",
+                _ => "",
+            }
+        )
+    }
+
+    /// The name of the cluster the vertex `v` belongs to, if any.
+    fn cluster(&self, v: usize, data: Option<&Hex>) -> Option<String> {
+        let _ = (v, data);
+        None
+    }
+}
+
+/// A lazy breadth-first traversal of a [`Sodg`], which is made
+/// by [`Sodg::bfs`].
+///
+/// It returns each reachable vertex once, together with its depth and
+/// the label of the edge it was reached by.
+pub struct Bfs<'a, const N: usize> {
+    /// The graph.
+    g: &'a Sodg<N>,
+    /// The vertices discovered, but not returned yet.
+    queue: std::collections::VecDeque<(usize, usize, Option<Label>)>,
+    /// The vertices discovered so far.
+    seen: HashSet<usize>,
+}
+
+/// A lazy depth-first traversal of a [`Sodg`], which is made
+/// by [`Sodg::dfs`].
+///
+/// It returns each reachable vertex once, together with its depth and
+/// the label of the edge it was reached by.
+pub struct Dfs<'a, const N: usize> {
+    /// The graph.
+    g: &'a Sodg<N>,
+    /// The vertices to visit, the next one on top.
+    stack: Vec<(usize, usize, Option<Label>)>,
+    /// The vertices already returned.
+    seen: HashSet<usize>,
+}
+
+/// A lazy iterator over all simple paths between two vertices,
+/// which is made by [`Sodg::all_simple_paths`].
+///
+/// Only one path is kept in memory at a time, together with the
+/// state of the depth-first search.
+pub struct SimplePaths<'a, const N: usize> {
+    /// The graph.
+    g: &'a Sodg<N>,
+    /// The vertex where paths end.
+    to: usize,
+    /// The vertices of the current path, each with the kids
+    /// not visited yet.
+    stack: Vec<(usize, Vec<usize>)>,
+    /// The vertices of the current path, for quick lookup.
+    visited: HashSet<usize>,
+    /// The path to return first, if `from` equals to `to`.
+    pending: Option<Vec<usize>>,
 }
 
 #[derive(PartialEq, Serialize, Deserialize, Clone)]
@@ -155,6 +541,13 @@ enum Persistence {
     Taken,
 }
 
+/// A change buffered in a [`Tx`].
+enum TxOp {
+    Add(usize),
+    Bind(usize, usize, Label),
+    Put(usize, Hex),
+}
+
 const BRANCH_NONE: usize = 0;
 const BRANCH_STATIC: usize = 1;
 
@@ -0,0 +1,438 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::tx::Growth;
+use crate::{Hex, Journal, Label, LoggedSodg, Sodg};
+use anyhow::{anyhow, Context, Result};
+use bincode::{deserialize, serialize};
+use serde::{Deserialize, Serialize};
+use std::fs::OpenOptions;
+use std::io::{BufReader, ErrorKind, Read, Write};
+use std::path::Path;
+
+/// The biggest record that may be written to the log or read from it,
+/// in bytes, to not allocate gigabytes because of a broken length.
+const MAX_RECORD: usize = 16 * 1024 * 1024;
+
+/// A change of the graph, as it is written to the log.
+#[derive(Serialize, Deserialize)]
+enum Record {
+    Add(usize),
+    Bind(usize, usize, Label),
+    Put(usize, Vec<u8>),
+    Delete(usize),
+    Unbind(usize, Label),
+}
+
+impl<const N: usize> Sodg<N> {
+    /// Make an empty graph, which writes all its changes to `w`.
+    ///
+    /// Each change is a record, prefixed by its length, as four bytes
+    /// in big-endian order. The `cap` is the capacity of the graph,
+    /// as in [`Sodg::empty`]. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut log = vec![];
+    /// let mut g = Sodg::<16>::open_log(&mut log, 256);
+    /// g.add(0).unwrap();
+    /// g.add(1).unwrap();
+    /// g.bind(0, 1, Label::Alpha(0)).unwrap();
+    /// let r = Sodg::<16>::replay_log(log.as_slice(), 256).unwrap();
+    /// assert_eq!(Some(1), r.kid(0, Label::Alpha(0)));
+    /// ```
+    pub fn open_log<W: Write>(w: W, cap: usize) -> LoggedSodg<N, W> {
+        LoggedSodg {
+            g: Self::empty(cap),
+            w,
+        }
+    }
+
+    /// Make a graph by replaying the log written by a [`LoggedSodg`].
+    ///
+    /// If the last record is not complete, it is ignored, since it's
+    /// most probably the result of a crash in the middle of writing.
+    ///
+    /// # Errors
+    ///
+    /// If the log can't be read, a complete record can't be parsed, is
+    /// too big, or refers to a vertex that is absent or doesn't fit
+    /// into the `cap`, an error will be returned.
+    pub fn replay_log<R: Read>(r: R, cap: usize) -> Result<Self> {
+        Ok(Self::replay_records(r, cap)?.0)
+    }
+
+    /// Open the journal in the file, replaying all records already
+    /// there and appending new ones to its end. The file is created,
+    /// if it doesn't exist. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let tmp = tempfile::TempDir::new().unwrap();
+    /// let path = tmp.path().join("g.journal");
+    /// let mut j = Sodg::<16>::open_journal(&path, 256).unwrap();
+    /// j.add(0).unwrap();
+    /// j.add(1).unwrap();
+    /// j.bind(0, 1, Label::Alpha(0)).unwrap();
+    /// drop(j);
+    /// let j = Sodg::<16>::open_journal(&path, 256).unwrap();
+    /// assert_eq!(Some(1), j.graph().kid(0, Label::Alpha(0)));
+    /// ```
+    ///
+    /// If the last record in the file is not complete, it is cut off,
+    /// so that new records are not appended to a broken one.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be opened or the journal can't be
+    /// replayed, an error will be returned.
+    pub fn open_journal(path: &Path, cap: usize) -> Result<Journal<N>> {
+        let file = OpenOptions::new()
+            .read(true)
+            .append(true)
+            .create(true)
+            .open(path)
+            .with_context(|| format!("Can't open {}", path.display()))?;
+        let (g, size) = Self::replay_records(BufReader::new(&file), cap)
+            .with_context(|| format!("Can't replay {}", path.display()))?;
+        file.set_len(size)
+            .with_context(|| format!("Can't truncate {}", path.display()))?;
+        Ok(LoggedSodg { g, w: file })
+    }
+
+    /// Replay all complete records, returning the graph and the
+    /// number of bytes they occupy.
+    fn replay_records<R: Read>(mut r: R, cap: usize) -> Result<(Self, u64)> {
+        let mut g = Self::empty(cap);
+        let mut pos = 0;
+        let mut size = 0;
+        loop {
+            let mut len = [0; 4];
+            if !Self::read_fully(&mut r, &mut len)? {
+                break;
+            }
+            let total = u32::from_be_bytes(len) as usize;
+            if total > MAX_RECORD {
+                return Err(anyhow!(
+                    "The record no.{pos} is {total} bytes long, while {MAX_RECORD} is the maximum"
+                ));
+            }
+            let mut bytes = vec![0; total];
+            if !Self::read_fully(&mut r, &mut bytes)? {
+                break;
+            }
+            let rec: Record =
+                deserialize(&bytes).with_context(|| format!("Can't parse the record no.{pos}"))?;
+            g.check_record(&rec)
+                .and_then(|()| g.apply_record(rec))
+                .with_context(|| format!("Can't apply the record no.{pos}"))?;
+            pos += 1;
+            size += len.len() as u64 + bytes.len() as u64;
+        }
+        Ok((g, size))
+    }
+
+    /// Make sure the record can be applied to the graph, without
+    /// applying it.
+    fn check_record(&self, rec: &Record) -> Result<()> {
+        let cap = self.vertices.capacity();
+        let exists = |v: usize| -> Result<()> {
+            if v >= cap {
+                return Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"));
+            }
+            if !self.alive(v) {
+                return Err(anyhow!("Can't find ν{v}"));
+            }
+            Ok(())
+        };
+        match rec {
+            Record::Add(v) => {
+                if *v >= cap {
+                    return Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"));
+                }
+            }
+            Record::Bind(v1, v2, a) => {
+                exists(*v1)?;
+                exists(*v2)?;
+                let vtx = self.vertices.get(*v1).unwrap();
+                if vtx.edges.get(a).is_none() && vtx.edges.len() >= N {
+                    return Err(anyhow!("Too many edges at ν{v1}, {N} is the maximum"));
+                }
+                Growth::default().bind(self, *v1, *v2)?;
+            }
+            Record::Put(v, _) | Record::Delete(v) | Record::Unbind(v, _) => exists(*v)?,
+        }
+        Ok(())
+    }
+
+    /// Apply the record to the graph, which must be checked
+    /// by [`Sodg::check_record`] first.
+    fn apply_record(&mut self, rec: Record) -> Result<()> {
+        match rec {
+            Record::Add(v) => self.add(v),
+            Record::Bind(v1, v2, a) => self.bind(v1, v2, a),
+            Record::Put(v, d) => self.put(v, &Hex::from_vec(d)),
+            Record::Delete(v) => self.remove(v)?,
+            Record::Unbind(v, a) => {
+                self.unbind(v, a);
+            }
+        }
+        Ok(())
+    }
+
+    /// Fill the buffer, returning `false` if the reader ends earlier.
+    fn read_fully<R: Read>(r: &mut R, buf: &mut [u8]) -> Result<bool> {
+        match r.read_exact(buf) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == ErrorKind::UnexpectedEof => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
+
+impl<const N: usize, W: Write> LoggedSodg<N, W> {
+    /// Add a vertex, see [`Sodg::add`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex doesn't fit into the capacity of the graph or the log
+    /// can't be written, an error will be returned and nothing will change.
+    pub fn add(&mut self, v: usize) -> Result<()> {
+        self.log(Record::Add(v))
+    }
+
+    /// Make an edge, see [`Sodg::bind`].
+    ///
+    /// # Errors
+    ///
+    /// If any of the vertices is absent, there is no room for the edge,
+    /// or the log can't be written, an error will be returned and nothing
+    /// will change.
+    pub fn bind(&mut self, v1: usize, v2: usize, a: Label) -> Result<()> {
+        self.log(Record::Bind(v1, v2, a))
+    }
+
+    /// Put data into a vertex, see [`Sodg::put`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex is absent or the log can't be written, an error
+    /// will be returned and nothing will change.
+    pub fn put(&mut self, v: usize, d: &Hex) -> Result<()> {
+        self.log(Record::Put(v, d.to_vec()))
+    }
+
+    /// Delete a vertex together with all edges pointing to it.
+    ///
+    /// # Errors
+    ///
+    /// If there is no such vertex or the log can't be written,
+    /// an error will be returned.
+    pub fn delete(&mut self, v: usize) -> Result<()> {
+        self.log(Record::Delete(v))
+    }
+
+    /// Remove an edge, see [`Sodg::unbind`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex is absent or the log can't be written, an error
+    /// will be returned and nothing will change.
+    pub fn unbind(&mut self, v: usize, a: Label) -> Result<()> {
+        self.log(Record::Unbind(v, a))
+    }
+
+    /// Get the graph, to read it.
+    pub const fn graph(&self) -> &Sodg<N> {
+        &self.g
+    }
+
+    /// Flush the log.
+    ///
+    /// # Errors
+    ///
+    /// If the log can't be flushed, an error will be returned.
+    pub fn flush(&mut self) -> Result<()> {
+        Ok(self.w.flush()?)
+    }
+
+    /// Check the record, write it to the log, and apply it to the graph.
+    fn log(&mut self, rec: Record) -> Result<()> {
+        self.g.check_record(&rec)?;
+        self.write(&rec)?;
+        self.g.apply_record(rec)
+    }
+
+    /// Write one record to the log.
+    fn write(&mut self, rec: &Record) -> Result<()> {
+        let bytes = serialize(rec)?;
+        if bytes.len() > MAX_RECORD {
+            return Err(anyhow!(
+                "The record is {} bytes long, while {MAX_RECORD} is the maximum",
+                bytes.len()
+            ));
+        }
+        let len = u32::try_from(bytes.len()).context("The record is too big")?;
+        self.w.write_all(&len.to_be_bytes())?;
+        self.w.write_all(&bytes)?;
+        Ok(())
+    }
+}
+
+#[test]
+fn replays_the_log() {
+    let mut log = vec![];
+    {
+        let mut g = Sodg::<16>::open_log(&mut log, 256);
+        g.add(0).unwrap();
+        g.add(1).unwrap();
+        g.add(2).unwrap();
+        g.bind(0, 1, Label::Alpha(0)).unwrap();
+        g.bind(1, 2, Label::Alpha(0)).unwrap();
+        g.put(2, &Hex::from_str_bytes("hello")).unwrap();
+        g.flush().unwrap();
+        assert_eq!(3, g.graph().len());
+    }
+    let mut r: Sodg<16> = Sodg::replay_log(log.as_slice(), 256).unwrap();
+    assert_eq!(3, r.len());
+    assert_eq!(Some(2), r.kid(1, Label::Alpha(0)));
+    assert_eq!("hello", r.data(2).unwrap().to_utf8().unwrap());
+    let empty: Sodg<16> = Sodg::replay_log(&[][..], 256).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn replays_truncated_log() {
+    let mut log = vec![];
+    let mut g = Sodg::<16>::open_log(&mut log, 256);
+    g.add(0).unwrap();
+    g.add(1).unwrap();
+    g.bind(0, 1, Label::Alpha(0)).unwrap();
+    for cut in 1..4 {
+        let r: Sodg<16> = Sodg::replay_log(&log[..log.len() - cut], 256).unwrap();
+        assert_eq!(2, r.len());
+        assert_eq!(None, r.kid(0, Label::Alpha(0)));
+    }
+}
+
+#[cfg(test)]
+use tempfile::TempDir;
+
+#[test]
+fn reopens_the_journal() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("g.journal");
+    {
+        let mut j = Sodg::<16>::open_journal(&path, 256).unwrap();
+        j.add(0).unwrap();
+        j.add(1).unwrap();
+        j.add(2).unwrap();
+        j.bind(0, 1, Label::Alpha(0)).unwrap();
+        j.bind(0, 2, Label::Alpha(1)).unwrap();
+        j.delete(2).unwrap();
+        assert!(j.delete(2).is_err());
+    }
+    let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+    file.write_all(&[0, 0, 0, 9, 1]).unwrap();
+    drop(file);
+    {
+        let mut j = Sodg::<16>::open_journal(&path, 256).unwrap();
+        assert_eq!(2, j.graph().len());
+        assert_eq!(None, j.graph().kid(0, Label::Alpha(1)));
+        j.put(1, &Hex::from_str_bytes("hello")).unwrap();
+    }
+    let j = Sodg::<16>::open_journal(&path, 256).unwrap();
+    assert_eq!(Some(1), j.graph().kid(0, Label::Alpha(0)));
+    assert_eq!("hello", j.graph().peek(1).unwrap().to_utf8().unwrap());
+}
+
+#[test]
+fn rejects_broken_records() {
+    let mut log = vec![];
+    let mut g = Sodg::<16>::open_log(&mut log, 256);
+    g.add(100).unwrap();
+    assert!(Sodg::<16>::replay_log(log.as_slice(), 16).is_err());
+    let mut log = vec![];
+    let mut g = Sodg::<16>::open_log(&mut log, 256);
+    g.add(0).unwrap();
+    g.write(&Record::Bind(0, 1, Label::Alpha(0))).unwrap();
+    assert!(Sodg::<16>::replay_log(log.as_slice(), 256).is_err());
+    let mut log = u32::MAX.to_be_bytes().to_vec();
+    log.extend_from_slice(&[0; 8]);
+    assert!(Sodg::<16>::replay_log(log.as_slice(), 256)
+        .unwrap_err()
+        .to_string()
+        .contains("maximum"));
+}
+
+#[test]
+fn rejects_broken_changes_before_writing() {
+    let mut log = vec![];
+    let mut g = Sodg::<16>::open_log(&mut log, 16);
+    g.add(0).unwrap();
+    assert!(g.add(16).is_err());
+    assert!(g.bind(0, 7, Label::Alpha(0)).is_err());
+    assert!(g.put(7, &Hex::from(42)).is_err());
+    assert!(g.unbind(7, Label::Alpha(0)).is_err());
+    assert!(g.delete(7).is_err());
+    assert_eq!(1, g.graph().len());
+    let r: Sodg<16> = Sodg::replay_log(log.as_slice(), 16).unwrap();
+    assert_eq!(1, r.len());
+}
+
+#[test]
+fn replays_removals() {
+    let mut log = vec![];
+    let mut g = Sodg::<16>::open_log(&mut log, 256);
+    for v in 0..3 {
+        g.add(v).unwrap();
+    }
+    g.bind(0, 1, Label::Alpha(0)).unwrap();
+    g.bind(0, 2, Label::Alpha(1)).unwrap();
+    g.bind(1, 2, Label::Alpha(0)).unwrap();
+    g.unbind(0, Label::Alpha(0)).unwrap();
+    g.delete(2).unwrap();
+    let expected = g.graph().clone();
+    let r: Sodg<16> = Sodg::replay_log(log.as_slice(), 256).unwrap();
+    r.assert_structurally_equal(&expected);
+    assert_eq!(2, r.len());
+    assert_eq!(None, r.kid(0, Label::Alpha(0)));
+    assert_eq!(None, r.kid(0, Label::Alpha(1)));
+}
+
+#[test]
+fn reopens_the_journal_after_rejected_changes() {
+    let tmp = TempDir::new().unwrap();
+    let path = tmp.path().join("g.journal");
+    {
+        let mut j = Sodg::<16>::open_journal(&path, 16).unwrap();
+        j.add(0).unwrap();
+        assert!(j.bind(0, 7, Label::Alpha(0)).is_err());
+        assert!(j.add(100).is_err());
+        assert_eq!(1, j.graph().len());
+    }
+    let mut j = Sodg::<16>::open_journal(&path, 16).unwrap();
+    assert_eq!(1, j.graph().len());
+    j.add(7).unwrap();
+    j.bind(0, 7, Label::Alpha(0)).unwrap();
+    drop(j);
+    let j = Sodg::<16>::open_journal(&path, 16).unwrap();
+    assert_eq!(Some(7), j.graph().kid(0, Label::Alpha(0)));
+}
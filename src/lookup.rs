@@ -0,0 +1,131 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Build an index of all edges, to make [`Sodg::kid_indexed`] fast.
+    ///
+    /// Once built, the index is kept in sync with all further changes
+    /// of the graph. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.build_index();
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// assert_eq!(Some(1), g.kid_indexed(0, Label::from_str("foo").unwrap()));
+    /// ```
+    pub fn build_index(&mut self) {
+        let mut idx = HashMap::new();
+        for v in self.keys() {
+            for (a, to) in self.kids(v) {
+                idx.insert((v, *a), *to);
+            }
+        }
+        self.index = Some(idx);
+    }
+
+    /// Find a kid of a vertex, by its edge name, using the index
+    /// built by [`Sodg::build_index`].
+    ///
+    /// If the index is not built, the edges of the vertex
+    /// are scanned, as in [`Sodg::kid`].
+    #[must_use]
+    pub fn kid_indexed(&self, v: usize, a: Label) -> Option<usize> {
+        self.index
+            .as_ref()
+            .map_or_else(|| self.kid(v, a), |idx| idx.get(&(v, a)).copied())
+    }
+
+    /// Build the index again, but only if it was built before.
+    pub(crate) fn reindex(&mut self) {
+        if self.index.is_some() {
+            self.build_index();
+        }
+    }
+}
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn keeps_index_in_sync() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("a").unwrap());
+    g.build_index();
+    g.bind(0, 2, Label::from_str("b").unwrap());
+    g.bind(0, 3, Label::from_str("a").unwrap());
+    g.rename_edge(
+        0,
+        Label::from_str("b").unwrap(),
+        Label::from_str("c").unwrap(),
+    )
+    .unwrap();
+    g.bind(3, 1, Label::from_str("x").unwrap());
+    g.insert_vertex_on_edge(
+        3,
+        Label::from_str("x").unwrap(),
+        Label::from_str("y").unwrap(),
+    )
+    .unwrap();
+    g.map_labels(|a| format!("{a}{a}")).unwrap();
+    g.erase(2);
+    for v in 0..8 {
+        for a in ["a", "b", "c", "x", "y", "aa", "bb", "cc", "xx", "yy"] {
+            let a = Label::from_str(a).unwrap();
+            if g.alive(v) {
+                assert_eq!(g.kid(v, a), g.kid_indexed(v, a));
+            } else {
+                assert_eq!(None, g.kid_indexed(v, a));
+            }
+        }
+    }
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(2, 0, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(1));
+    g.build_index();
+    let core = g.k_core(2);
+    assert_eq!(None, core.kid(2, Label::Alpha(1)));
+    assert_eq!(None, core.kid_indexed(2, Label::Alpha(1)));
+    assert_eq!(Some(0), core.kid_indexed(2, Label::Alpha(0)));
+}
+
+#[test]
+fn scans_without_index() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    assert_eq!(Some(1), g.kid_indexed(0, Label::Alpha(0)));
+    assert_eq!(None, g.kid_indexed(0, Label::Alpha(1)));
+}
@@ -18,11 +18,24 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{Label, Persistence, Sodg};
+use crate::{Label, MergeDecision, MergePlan, Persistence, Sodg};
 use anyhow::{anyhow, Result};
 use log::debug;
 use std::collections::{HashMap, HashSet};
 
+/// A vertex of the right graph, which is being merged into the
+/// left one by [`Sodg::merge_with`].
+struct Frame {
+    /// The vertex of the left graph.
+    left: usize,
+    /// The vertex of the right graph.
+    right: usize,
+    /// The kids of the right vertex not merged yet, in reverse order.
+    kids: Vec<(Label, usize)>,
+    /// The labels of the kids renamed while merging.
+    renamed: HashSet<Label>,
+}
+
 impl<const N: usize> Sodg<N> {
     /// Merge another graph into the current one.
     ///
@@ -39,9 +52,46 @@ impl<const N: usize> Sodg<N> {
     ///
     /// If it's impossible to merge, an error will be returned.
     pub fn merge(&mut self, g: &Self, left: usize, right: usize) -> Result<()> {
+        self.merge_with(g, left, right, |_, _, _| MergeDecision::Unify)
+    }
+
+    /// Merge another graph into the current one, the same way as
+    /// [`Sodg::merge`] does, asking the `resolver` what to do when both
+    /// graphs have a kid with the same label.
+    ///
+    /// The `resolver` gets the label, the kid in the current graph, and
+    /// the kid in the merged graph. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, MergeDecision, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// let extra = g.clone();
+    /// let other = Label::from_str("bar").unwrap();
+    /// g.merge_with(&extra, 0, 0, |_, _, _| MergeDecision::Rename(other)).unwrap();
+    /// assert_eq!(3, g.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If it's impossible to merge or the `resolver` rejects a conflict,
+    /// an error will be returned.
+    pub fn merge_with<F>(
+        &mut self,
+        g: &Self,
+        left: usize,
+        right: usize,
+        mut resolver: F,
+    ) -> Result<()>
+    where
+        F: FnMut(Label, usize, usize) -> MergeDecision,
+    {
         let mut mapped = HashMap::new();
         let before = self.len();
-        self.merge_rec(g, left, right, &mut mapped)?;
+        self.merge_tree(g, left, right, &mut mapped, &mut resolver)?;
         let merged = mapped.len();
         let scope = g.len();
         if merged != scope {
@@ -65,48 +115,185 @@ impl<const N: usize> Sodg<N> {
         Ok(())
     }
 
-    /// Merge two trees recursively, ignoring the nodes already `mapped`.
+    /// Calculate the changes [`Sodg::merge`] would make, without
+    /// making them.
+    ///
+    /// The merge is made on a copy of the graph, which is then compared
+    /// with the original one. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// let mut extra : Sodg<16> = Sodg::empty(256);
+    /// extra.add(0);
+    /// extra.add(1);
+    /// extra.bind(0, 1, Label::Alpha(0));
+    /// let plan = g.merge_plan(&extra, 0, 0).unwrap();
+    /// assert_eq!(vec![1], plan.added);
+    /// assert_eq!(vec![(0, Label::Alpha(0), 1)], plan.bound);
+    /// assert_eq!(1, g.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If it's impossible to merge, an error will be returned.
+    pub fn merge_plan(&self, g: &Self, left: usize, right: usize) -> Result<MergePlan> {
+        let mut after = self.clone();
+        after.merge(g, left, right)?;
+        let mut keys = after.keys();
+        keys.sort_unstable();
+        let mut plan = MergePlan::default();
+        for v in keys {
+            let alive = self.alive(v);
+            if !alive {
+                plan.added.push(v);
+            }
+            for (a, to) in after.kids(v) {
+                if !alive || self.kid(v, *a) != Some(*to) {
+                    plan.bound.push((v, *a, *to));
+                }
+            }
+            if let Some(d) = after.peek(v) {
+                if !alive || self.peek(v) != Some(d) {
+                    plan.overwritten.push(v);
+                }
+            }
+        }
+        Ok(plan)
+    }
+
+    /// Merge another graph into the current one, mapping the vertex zero
+    /// of it to the vertex zero of the current graph.
+    ///
+    /// It is the same as [`Sodg::merge`] with both roots equal to zero.
+    ///
+    /// # Errors
+    ///
+    /// If it's impossible to merge, an error will be returned.
+    pub fn merge_into_root(&mut self, g: &Self) -> Result<()> {
+        self.merge(g, 0, 0)
+    }
+
+    /// Merge another graph into the current one, mapping the `right` root
+    /// to the `left` one, where `None` means the vertex zero.
+    ///
+    /// # Errors
+    ///
+    /// If it's impossible to merge, an error will be returned.
+    pub fn merge_roots(
+        &mut self,
+        g: &Self,
+        left: Option<usize>,
+        right: Option<usize>,
+    ) -> Result<()> {
+        self.merge(g, left.unwrap_or(0), right.unwrap_or(0))
+    }
+
+    /// Merge two trees, ignoring the nodes already `mapped`.
     ///
     /// The `right` vertex is mapped to the `left` vertex. The decisions about
-    /// their kids are made recursively.
+    /// their kids are made in depth-first order, keeping the vertices being
+    /// merged in an explicit stack, instead of recursion, in order to not
+    /// overflow the call stack on deep graphs.
     ///
     /// The `mapped` is a key-value map, where the key is a vertex from the right
     /// graph, which is mapped to a vertex from the left graph.
     ///
+    /// The `resolver` decides what to do with kids with the same labels.
+    ///
     /// # Errors
     ///
     /// If it's impossible to merge, an error will be returned.
     #[allow(clippy::option_if_let_else)]
-    fn merge_rec(
+    fn merge_tree(
         &mut self,
         g: &Self,
         left: usize,
         right: usize,
         mapped: &mut HashMap<usize, usize>,
+        resolver: &mut dyn FnMut(Label, usize, usize) -> MergeDecision,
     ) -> Result<()> {
-        if mapped.contains_key(&right) {
-            return Ok(());
-        }
-        mapped.insert(right, left);
-        if g.vertices.get(right).unwrap().persistence != Persistence::Empty {
-            self.put(left, &g.vertices.get(right).unwrap().data);
-        }
-        for (a, to) in g.kids(right) {
-            let matched = if let Some(t) = self.kid(left, *a) {
+        let mut stack = vec![];
+        self.merge_enter(g, left, right, mapped, &mut stack);
+        while let Some(top) = stack.last_mut() {
+            let left = top.left;
+            let Some((a, to)) = top.kids.pop() else {
+                let done = stack.pop().unwrap();
+                self.merge_leave(g, &done, mapped);
+                continue;
+            };
+            let mut b = a;
+            if let Some(t) = self.kid(left, a) {
+                if mapped.get(&to) != Some(&t) {
+                    match resolver(a, t, to) {
+                        MergeDecision::Unify => {}
+                        MergeDecision::Rename(n) => {
+                            if self.kid(left, n).is_some() {
+                                return Err(anyhow!(
+                                    "Can't rename ν{left}.{a}, ν{left}.{n} exists"
+                                ));
+                            }
+                            top.renamed.insert(a);
+                            b = n;
+                        }
+                        MergeDecision::Reject => {
+                            return Err(anyhow!("The conflict at ν{left}.{a} is rejected"));
+                        }
+                    }
+                }
+            }
+            let matched = if let Some(t) = self.kid(left, b) {
                 t
-            } else if let Some(t) = mapped.get(to) {
-                self.bind(left, *t, *a);
+            } else if let Some(t) = mapped.get(&to) {
+                self.bind(left, *t, b);
                 *t
             } else {
                 let id = self.next_id();
                 self.add(id);
-                self.bind(left, id, *a);
+                self.bind(left, id, b);
                 id
             };
-            self.merge_rec(g, matched, *to, mapped)?;
+            self.merge_enter(g, matched, to, mapped, &mut stack);
+        }
+        Ok(())
+    }
+
+    /// Start merging the `right` vertex into the `left` one, unless
+    /// it's already `mapped`, putting it on top of the `stack`.
+    fn merge_enter(
+        &mut self,
+        g: &Self,
+        left: usize,
+        right: usize,
+        mapped: &mut HashMap<usize, usize>,
+        stack: &mut Vec<Frame>,
+    ) {
+        if mapped.contains_key(&right) {
+            return;
         }
-        for (a, to) in g.kids(right) {
-            if let Some(first) = self.kid(left, *a) {
+        mapped.insert(right, left);
+        if g.vertices.get(right).unwrap().persistence != Persistence::Empty {
+            self.put(left, &g.vertices.get(right).unwrap().data);
+        }
+        let mut kids: Vec<(Label, usize)> = g.kids(right).map(|(a, to)| (*a, *to)).collect();
+        kids.reverse();
+        stack.push(Frame {
+            left,
+            right,
+            kids,
+            renamed: HashSet::new(),
+        });
+    }
+
+    /// Finish merging the vertex, when all its kids are merged, joining
+    /// the kids that ended up in two different vertices.
+    fn merge_leave(&mut self, g: &Self, done: &Frame, mapped: &HashMap<usize, usize>) {
+        for (a, to) in g
+            .kids(done.right)
+            .filter(|(a, _)| !done.renamed.contains(*a))
+        {
+            if let Some(first) = self.kid(done.left, *a) {
                 if let Some(second) = mapped.get(to) {
                     if first != *second {
                         self.join(first, *second);
@@ -114,7 +301,6 @@ impl<const N: usize> Sodg<N> {
                 }
             }
         }
-        Ok(())
     }
 
     fn join(&mut self, left: usize, right: usize) {
@@ -127,6 +313,7 @@ impl<const N: usize> Sodg<N> {
             }
             self.vertices.insert(v, nv);
         }
+        self.reindex();
         let kids = self
             .kids(right)
             .map(|(a, v)| (*a, *v))
@@ -140,6 +327,7 @@ impl<const N: usize> Sodg<N> {
             self.bind(left, e.1, e.0);
         }
         self.vertices.remove(right);
+        self.recount();
     }
 }
 
@@ -397,3 +585,95 @@ fn two_big_graphs() {
     g.merge(&extra, 0, 0).unwrap();
     assert_eq!(4, g.len());
 }
+
+#[test]
+fn merges_into_root() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    let mut extra = Sodg::empty(256);
+    extra.add(0);
+    extra.add(1);
+    extra.bind(0, 1, Label::from_str("bar").unwrap());
+    g.merge_into_root(&extra).unwrap();
+    assert_eq!(2, g.len());
+    extra.add(7);
+    extra.add(8);
+    extra.bind(7, 8, Label::from_str("foo").unwrap());
+    let sub = extra.slice(7).unwrap();
+    g.merge_roots(&sub, Some(1), Some(7)).unwrap();
+    assert_eq!(3, g.len());
+    assert!(g.kid(1, Label::from_str("foo").unwrap()).is_some());
+}
+
+#[test]
+fn merges_with_resolver() {
+    let foo = Label::from_str("foo").unwrap();
+    let bar = Label::from_str("bar").unwrap();
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, foo);
+    g.put(1, &Hex::from_str_bytes("left"));
+    let mut extra: Sodg<16> = Sodg::empty(256);
+    extra.add(0);
+    extra.add(5);
+    extra.bind(0, 5, foo);
+    extra.put(5, &Hex::from_str_bytes("right"));
+    let mut seen = vec![];
+    g.merge_with(&extra, 0, 0, |a, l, r| {
+        seen.push((a, l, r));
+        MergeDecision::Rename(bar)
+    })
+    .unwrap();
+    assert_eq!(vec![(foo, 1, 5)], seen);
+    assert_eq!(3, g.len());
+    assert_eq!("left", g.peek(1).unwrap().to_utf8().unwrap());
+    let renamed = g.kid(0, bar).unwrap();
+    assert_eq!("right", g.peek(renamed).unwrap().to_utf8().unwrap());
+    let err = g
+        .merge_with(&extra, 0, 0, |_, _, _| MergeDecision::Reject)
+        .unwrap_err();
+    assert!(err.to_string().contains("ν0.foo is rejected"));
+    g.merge_with(&extra, 0, 0, |_, _, _| MergeDecision::Unify)
+        .unwrap();
+    assert_eq!(3, g.len());
+}
+
+#[test]
+fn plans_merge_without_changes() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.put(1, &Hex::from(1));
+    let mut extra: Sodg<16> = Sodg::empty(256);
+    extra.add(0);
+    extra.add(1);
+    extra.add(2);
+    extra.bind(0, 1, Label::from_str("foo").unwrap());
+    extra.bind(0, 2, Label::from_str("bar").unwrap());
+    extra.put(1, &Hex::from(2));
+    let before = g.clone();
+    let plan = g.merge_plan(&extra, 0, 0).unwrap();
+    g.assert_structurally_equal(&before);
+    assert_eq!(vec![2], plan.added);
+    assert_eq!(vec![(0, Label::from_str("bar").unwrap(), 2)], plan.bound);
+    assert_eq!(vec![1], plan.overwritten);
+    g.merge(&extra, 0, 0).unwrap();
+    assert_eq!(MergePlan::default(), g.merge_plan(&extra, 0, 0).unwrap());
+}
+
+#[test]
+fn merges_deepest_chain() {
+    let depth = crate::MAX_BRANCH_SIZE;
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    let extra: Sodg<16> = Sodg::chain(depth, Label::Alpha(0)).unwrap();
+    g.merge(&extra, 0, 0).unwrap();
+    assert_eq!(depth, g.len());
+    let mut v = 0;
+    for _ in 1..depth {
+        v = g.kid(v, Label::Alpha(0)).unwrap();
+    }
+    assert_eq!(None, g.kid(v, Label::Alpha(0)));
+}
@@ -0,0 +1,95 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Result};
+
+impl<const N: usize> Sodg<N> {
+    /// Give a human-readable name to a vertex.
+    ///
+    /// Names are not related to IDs of vertices and don't affect
+    /// the structure of the graph. A vertex may have only one name, which
+    /// replaces the previous one. The name goes away together with the
+    /// vertex, when it's removed or collected as garbage, and is not
+    /// saved by [`Sodg::save`]. For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// g.set_vertex_label(42, "answer").unwrap();
+    /// assert_eq!("answer", g.vertex_label(42).unwrap());
+    /// assert_eq!(42, g.vertex_by_label("answer").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the vertex is absent or the name is already taken by
+    /// another vertex, an error will be returned.
+    pub fn set_vertex_label(&mut self, v: usize, name: &str) -> Result<()> {
+        if !self.alive(v) {
+            return Err(anyhow!("Can't find ν{v}"));
+        }
+        if let Some(other) = self.named.get(name) {
+            if *other != v {
+                return Err(anyhow!("The name '{name}' is already given to ν{other}"));
+            }
+        }
+        if let Some(before) = self.names.insert(v, name.to_string()) {
+            self.named.remove(&before);
+        }
+        self.named.insert(name.to_string(), v);
+        Ok(())
+    }
+
+    /// Get the human-readable name of a vertex, if it has one.
+    #[must_use]
+    pub fn vertex_label(&self, v: usize) -> Option<&str> {
+        self.names.get(&v).map(String::as_str)
+    }
+
+    /// Find a vertex by its human-readable name.
+    #[must_use]
+    pub fn vertex_by_label(&self, name: &str) -> Option<usize> {
+        self.named.get(name).copied()
+    }
+}
+
+#[test]
+fn renames_vertex() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(1);
+    g.set_vertex_label(1, "first").unwrap();
+    g.set_vertex_label(1, "second").unwrap();
+    assert_eq!("second", g.vertex_label(1).unwrap());
+    assert!(g.vertex_by_label("first").is_none());
+    assert_eq!(1, g.vertex_by_label("second").unwrap());
+}
+
+#[test]
+fn rejects_duplicate_names() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(1);
+    g.add(2);
+    g.set_vertex_label(1, "foo").unwrap();
+    assert!(g.set_vertex_label(2, "foo").is_err());
+    assert!(g.set_vertex_label(3, "bar").is_err());
+    assert!(g.vertex_label(2).is_none());
+}
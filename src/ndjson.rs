@@ -0,0 +1,189 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::stream::VertexJson;
+use crate::{Hex, Label, Sodg};
+use anyhow::{anyhow, Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt};
+
+impl<const N: usize> Sodg<N> {
+    /// Write the graph to an async writer, as newline-delimited JSON,
+    /// one vertex per line, in the order of their IDs.
+    ///
+    /// Each line is a JSON object with `id`, `data` (if present),
+    /// and `edges`, in the same format as in [`Sodg::stream_vertices`].
+    ///
+    /// This function is available only with the `tokio` feature.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to write, an error will be returned.
+    #[allow(clippy::future_not_send)]
+    pub async fn to_ndjson_stream<W: AsyncWrite + Unpin>(&self, writer: &mut W) -> Result<()> {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        for v in keys {
+            let mut line = serde_json::to_string(&self.v_json(v))
+                .with_context(|| format!("Can't print ν{v} to JSON"))?;
+            line.push('\n');
+            writer
+                .write_all(line.as_bytes())
+                .await
+                .with_context(|| format!("Can't write ν{v}"))?;
+        }
+        writer.flush().await.context("Can't flush")?;
+        Ok(())
+    }
+
+    /// Read the graph from an async reader, which provides newline-delimited
+    /// JSON, previously written by [`Sodg::to_ndjson_stream`].
+    ///
+    /// Vertices may arrive in any order. An edge is kept aside, keyed by
+    /// the vertex it points to, until that vertex arrives, and only then
+    /// is added to the graph.
+    ///
+    /// This function is available only with the `tokio` feature.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to read or parse, if an ID of a vertex doesn't fit
+    /// into the `cap`, or if some edges point to vertices that never
+    /// arrived, an error will be returned.
+    #[allow(clippy::future_not_send)]
+    pub async fn from_ndjson_stream<R: AsyncBufRead + Unpin>(
+        reader: R,
+        cap: usize,
+    ) -> Result<Self> {
+        let mut g = Self::empty(cap);
+        let mut arrived = HashSet::new();
+        let mut pending: HashMap<usize, Vec<(usize, Label)>> = HashMap::new();
+        let mut lines = reader.lines();
+        let mut pos = 0;
+        while let Some(line) = lines
+            .next_line()
+            .await
+            .with_context(|| format!("Can't read line no.{}", pos + 1))?
+        {
+            pos += 1;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let vtx: VertexJson = serde_json::from_str(&line)
+                .with_context(|| format!("Can't parse vertex at line no.{pos}"))?;
+            g.try_add_if_absent(vtx.id)?;
+            arrived.insert(vtx.id);
+            if let Some(d) = &vtx.data {
+                g.put(
+                    vtx.id,
+                    &Hex::from_str(d)
+                        .with_context(|| format!("Can't parse data of ν{}", vtx.id))?,
+                );
+            }
+            for e in &vtx.edges {
+                if e.to >= cap {
+                    return Err(anyhow!(
+                        "The edge ν{}.{} points to ν{}, which doesn't fit into {cap} vertices",
+                        vtx.id,
+                        e.a,
+                        e.to
+                    ));
+                }
+                let a = Label::from_str(&e.a)?;
+                if arrived.contains(&e.to) {
+                    g.bind(vtx.id, e.to, a);
+                } else {
+                    pending.entry(e.to).or_default().push((vtx.id, a));
+                }
+            }
+            for (from, a) in pending.remove(&vtx.id).unwrap_or_default() {
+                g.bind(from, vtx.id, a);
+            }
+        }
+        if let Some((to, edges)) = pending.iter().min_by_key(|(to, _)| **to) {
+            let (from, a) = edges[0];
+            return Err(anyhow!(
+                "The edge ν{from}.{a} points to ν{to}, which never arrived, with {} more edge(s) like it",
+                pending.values().map(Vec::len).sum::<usize>() - 1
+            ));
+        }
+        Ok(g)
+    }
+}
+
+#[tokio::test]
+async fn writes_one_vertex_per_line() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.put(1, &Hex::from(42));
+    let mut buf = vec![];
+    g.to_ndjson_stream(&mut buf).await.unwrap();
+    let txt = String::from_utf8(buf).unwrap();
+    let lines: Vec<&str> = txt.lines().collect();
+    assert_eq!(2, lines.len());
+    assert_eq!("{\"id\":0,\"edges\":[{\"a\":\"α0\",\"to\":1}]}", lines[0]);
+    assert!(lines[1].starts_with("{\"id\":1,\"data\":"));
+}
+
+#[tokio::test]
+async fn reads_vertices_in_any_order() {
+    let txt =
+        "{\"id\":1,\"data\":\"00-00-00-00-00-00-00-2A\",\"edges\":[{\"a\":\"α0\",\"to\":2}]}\n\
+        {\"id\":0,\"edges\":[{\"a\":\"α0\",\"to\":1}]}\n\
+        {\"id\":2,\"edges\":[]}\n";
+    let mut g: Sodg<16> = Sodg::from_ndjson_stream(txt.as_bytes(), 256).await.unwrap();
+    assert_eq!(3, g.len());
+    assert_eq!(Some(1), g.kid(0, Label::Alpha(0)));
+    assert_eq!(Some(2), g.kid(1, Label::Alpha(0)));
+    assert_eq!(42, g.data(1).unwrap().to_i64().unwrap());
+}
+
+#[tokio::test]
+async fn reads_what_it_writes() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.put(1, &Hex::from(42));
+    let mut buf = vec![];
+    g.to_ndjson_stream(&mut buf).await.unwrap();
+    let after: Sodg<16> = Sodg::from_ndjson_stream(buf.as_slice(), 256).await.unwrap();
+    g.assert_structurally_equal(&after);
+}
+
+#[tokio::test]
+async fn fails_on_missing_vertex() {
+    let txt = "{\"id\":0,\"edges\":[{\"a\":\"α0\",\"to\":1}]}\n";
+    let r: Result<Sodg<16>> = Sodg::from_ndjson_stream(txt.as_bytes(), 256).await;
+    assert!(r.unwrap_err().to_string().contains("never arrived"));
+}
+
+#[tokio::test]
+async fn rejects_too_big_vertex() {
+    let txt = "{\"id\":100000000,\"edges\":[]}\n";
+    let r: Result<Sodg<16>> = Sodg::from_ndjson_stream(txt.as_bytes(), 16).await;
+    assert!(r.is_err());
+    let txt = "{\"id\":0,\"edges\":[{\"a\":\"α0\",\"to\":16}]}\n";
+    let r: Result<Sodg<16>> = Sodg::from_ndjson_stream(txt.as_bytes(), 16).await;
+    assert!(r.unwrap_err().to_string().contains("doesn't fit"));
+}
@@ -18,11 +18,13 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::{Hex, Label};
+use crate::{Hex, Label, VertexEventKind};
 use crate::{Persistence, Sodg, BRANCH_NONE, BRANCH_STATIC};
-use anyhow::Context;
+use anyhow::{anyhow, Context, Result};
 #[cfg(debug_assertions)]
 use log::trace;
+use std::collections::HashMap;
+use std::str::FromStr;
 
 impl<const N: usize> Sodg<N> {
     /// Add a new vertex `v1` to itself.
@@ -79,8 +81,15 @@ impl<const N: usize> Sodg<N> {
     pub fn bind(&mut self, v1: usize, v2: usize, a: Label) {
         let mut ours = self.vertices.get(v1).unwrap().branch;
         let theirs = self.vertices.get(v2).unwrap().branch;
+        let before = ours;
         let vtx1 = self.vertices.get_mut(v1).unwrap();
+        let replaced = vtx1.edges.get(&a).copied();
         vtx1.edges.insert(a, v2);
+        self.sort_kids(v1);
+        if let Some(idx) = &mut self.index {
+            idx.insert((v1, a), v2);
+        }
+        let vtx1 = self.vertices.get_mut(v1).unwrap();
         if ours == BRANCH_STATIC {
             if theirs == BRANCH_STATIC {
                 for b in self.branches.iter_mut() {
@@ -104,6 +113,14 @@ impl<const N: usize> Sodg<N> {
                 self.branches.get_mut(ours).unwrap().push(v2);
             }
         }
+        self.move_store(v1, before);
+        self.move_store(v2, theirs);
+        if let Some(old) = replaced {
+            self.notify(v1, VertexEventKind::OutgoingEdgeRemoved, old);
+            self.notify(old, VertexEventKind::IncomingEdgeRemoved, v1);
+        }
+        self.notify(v1, VertexEventKind::OutgoingEdgeAdded, v2);
+        self.notify(v2, VertexEventKind::IncomingEdgeAdded, v1);
         #[cfg(debug_assertions)]
         trace!(
             "#bind: edge added ν{}(b={}).{} → ν{}(b={})",
@@ -113,6 +130,12 @@ impl<const N: usize> Sodg<N> {
             v2,
             self.vertices.get(v2).unwrap().branch,
         );
+        if replaced != Some(v2) {
+            self.refer(v2);
+            if let Some(old) = replaced {
+                self.release(vec![old]);
+            }
+        }
     }
 
     /// Set vertex data.
@@ -138,6 +161,7 @@ impl<const N: usize> Sodg<N> {
         vtx.persistence = Persistence::Stored;
         vtx.data = d.clone();
         *self.stores.get_mut(vtx.branch).unwrap() += 1;
+        self.notify(v, VertexEventKind::DataChanged, v);
         #[cfg(debug_assertions)]
         trace!("#put: data of ν{v} set to {d}");
     }
@@ -180,21 +204,22 @@ impl<const N: usize> Sodg<N> {
                 *s -= 1;
                 if *s == 0 {
                     let members = self.branches.get_mut(branch).unwrap();
-                    for v in members.into_iter() {
-                        self.vertices.get_mut(v).unwrap().branch = BRANCH_NONE;
-                    }
+                    let dead: Vec<usize> = members.into_iter().collect();
+                    members.clear();
                     #[cfg(debug_assertions)]
                     trace!(
                         "#data: branch no.{} destroyed {} vertices as garbage: {}",
                         branch,
-                        members.len(),
-                        members
-                            .into_iter()
+                        dead.len(),
+                        dead.iter()
                             .map(|v| format!("ν{v}"))
                             .collect::<Vec<String>>()
                             .join(", ")
                     );
-                    members.clear();
+                    for m in dead {
+                        self.vertices.get_mut(m).unwrap().branch = BRANCH_NONE;
+                        self.forget(m);
+                    }
                 }
                 #[cfg(debug_assertions)]
                 trace!("#data: data of ν{v} retrieved");
@@ -209,7 +234,8 @@ impl<const N: usize> Sodg<N> {
         }
     }
 
-    /// Find all kids of a vertex.
+    /// Find all kids of a vertex, in the order of the labels
+    /// of their edges.
     ///
     /// For example:
     ///
@@ -255,6 +281,12 @@ impl<const N: usize> Sodg<N> {
             .iter()
     }
 
+    /// Find all kids of a vertex, as [`Sodg::kids`] does, skipping
+    /// those that were already collected as garbage by [`Sodg::data`].
+    pub(crate) fn live_kids(&self, v: usize) -> impl Iterator<Item = (&Label, &usize)> + '_ {
+        self.kids(v).filter(|(_, to)| self.alive(**to))
+    }
+
     /// Find a kid of a vertex, by its edge name, and return the ID of the vertex found.
     ///
     /// For example:
@@ -278,15 +310,346 @@ impl<const N: usize> Sodg<N> {
     pub fn kid(&self, v: usize, a: Label) -> Option<usize> {
         for e in &self.vertices.get(v).unwrap().edges {
             if *e.0 == a {
+                if self.tracing {
+                    self.trace_edge(v, a);
+                }
                 return Some(*e.1);
             }
         }
         None
     }
-}
 
-#[cfg(test)]
-use std::str::FromStr;
+    /// Change the label of an edge, keeping its target.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("old").unwrap());
+    /// g.rename_edge(0, Label::from_str("old").unwrap(), Label::from_str("new").unwrap()).unwrap();
+    /// assert_eq!(Some(1), g.kid(0, Label::from_str("new").unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is no edge with the `old` label or there is already an edge
+    /// with the `new` one, an error will be returned.
+    ///
+    /// # Panics
+    ///
+    /// If vertex `v` is absent, it will panic.
+    pub fn rename_edge(&mut self, v: usize, old: Label, new: Label) -> Result<()> {
+        let to = self
+            .kid(v, old)
+            .with_context(|| format!("There is no edge ν{v}.{old}"))?;
+        if old == new {
+            return Ok(());
+        }
+        if let Some(other) = self.kid(v, new) {
+            return Err(anyhow!(
+                "The edge ν{v}.{new} already exists, pointing to ν{other}"
+            ));
+        }
+        let edges = &mut self.vertices.get_mut(v).unwrap().edges;
+        edges.remove(&old);
+        edges.insert(new, to);
+        self.sort_kids(v);
+        if let Some(idx) = &mut self.index {
+            idx.remove(&(v, old));
+            idx.insert((v, new), to);
+        }
+        if let Some(m) = self.attrs.remove(&(v, old)) {
+            self.attrs.insert((v, new), m);
+        }
+        #[cfg(debug_assertions)]
+        trace!("#rename_edge: edge ν{v}.{old} renamed to ν{v}.{new}");
+        Ok(())
+    }
+
+    /// Change the labels of all edges in the graph, using the function `f`,
+    /// which gets the old label as a string and returns the new one.
+    ///
+    /// Either all labels are changed or none of them. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// g.map_labels(|a| a.replace("foo", "bar")).unwrap();
+    /// assert_eq!(Some(1), g.kid(0, Label::from_str("bar").unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If a new label can't be parsed or two edges of the same vertex
+    /// get the same label, an error will be returned.
+    ///
+    /// # Panics
+    ///
+    /// May panic if vertices are absent (should never happen, though).
+    pub fn map_labels<F>(&mut self, f: F) -> Result<()>
+    where
+        F: Fn(&str) -> String,
+    {
+        let mut plan = vec![];
+        for v in self.keys() {
+            let mut renamed: Vec<(Label, Label, usize)> = vec![];
+            for (a, to) in self.kids(v) {
+                let txt = f(&a.to_string());
+                let new = Label::from_str(&txt)
+                    .with_context(|| format!("Can't rename ν{v}.{a} to '{txt}'"))?;
+                if renamed.iter().any(|(_, b, _)| *b == new) {
+                    return Err(anyhow!("More than one edge of ν{v} is renamed to '{new}'"));
+                }
+                renamed.push((*a, new, *to));
+            }
+            plan.push((v, renamed));
+        }
+        let mut attrs = HashMap::new();
+        for (v, renamed) in plan {
+            let edges = &mut self.vertices.get_mut(v).unwrap().edges;
+            edges.clear();
+            for (old, new, to) in renamed {
+                edges.insert(new, to);
+                if let Some(m) = self.attrs.remove(&(v, old)) {
+                    attrs.insert((v, new), m);
+                }
+            }
+            self.sort_kids(v);
+        }
+        self.attrs = attrs;
+        self.reindex();
+        #[cfg(debug_assertions)]
+        trace!("#map_labels: all labels renamed");
+        Ok(())
+    }
+
+    /// Split an edge, putting a new vertex in the middle of it.
+    ///
+    /// The edge `from.a` is redirected to a new vertex, which gets an edge
+    /// labeled `b` to the vertex, where the edge `from.a` was pointing to.
+    /// The ID of the new vertex is returned. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let mid = g.insert_vertex_on_edge(0, Label::Alpha(0), Label::Alpha(1)).unwrap();
+    /// assert_eq!(Some(mid), g.kid(0, Label::Alpha(0)));
+    /// assert_eq!(Some(1), g.kid(mid, Label::Alpha(1)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is no edge `from.a`, an error will be returned.
+    pub fn insert_vertex_on_edge(&mut self, from: usize, a: Label, b: Label) -> Result<usize> {
+        if !self.alive(from) {
+            return Err(anyhow!("Can't find ν{from}"));
+        }
+        let to = self
+            .kid(from, a)
+            .with_context(|| format!("There is no edge ν{from}.{a}"))?;
+        let mid = self.next_id();
+        self.add(mid);
+        self.bind(mid, to, b);
+        self.bind(from, mid, a);
+        #[cfg(debug_assertions)]
+        trace!("#insert_vertex_on_edge: ν{mid} inserted into ν{from}.{a}");
+        Ok(mid)
+    }
+
+    /// Remove the vertex together with all edges that point to it.
+    pub(crate) fn remove(&mut self, v: usize) -> Result<()> {
+        if !self.alive(v) {
+            return Err(anyhow!("Can't find ν{v}"));
+        }
+        for u in self.keys() {
+            let incoming: Vec<Label> = self
+                .kids(u)
+                .filter(|(_, to)| **to == v)
+                .map(|(a, _)| *a)
+                .collect();
+            for a in incoming {
+                self.unbind(u, a);
+            }
+        }
+        self.erase(v);
+        Ok(())
+    }
+
+    /// Remove the edge `v.a`, returning the vertex it was pointing to,
+    /// or `None` if there is no such edge. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// assert_eq!(Some(1), g.unbind(0, Label::Alpha(0)));
+    /// assert_eq!(None, g.unbind(0, Label::Alpha(0)));
+    /// ```
+    ///
+    /// If the graph is made by [`Sodg::with_autogc`] and it was the last
+    /// edge pointing to the vertex, the vertex is removed too.
+    ///
+    /// # Panics
+    ///
+    /// If the vertex `v` is outside of the capacity of the graph, it will panic.
+    pub fn unbind(&mut self, v: usize, a: Label) -> Option<usize> {
+        let to = *self.vertices.get(v).unwrap().edges.get(&a)?;
+        self.vertices.get_mut(v).unwrap().edges.remove(&a);
+        self.sort_kids(v);
+        if let Some(idx) = &mut self.index {
+            idx.remove(&(v, a));
+        }
+        self.attrs.remove(&(v, a));
+        self.notify(v, VertexEventKind::OutgoingEdgeRemoved, to);
+        self.notify(to, VertexEventKind::IncomingEdgeRemoved, v);
+        #[cfg(debug_assertions)]
+        trace!("#unbind: edge ν{v}.{a} → ν{to} removed");
+        self.release(vec![to]);
+        Some(to)
+    }
+
+    /// Keep the edges of a vertex ordered by their labels, which
+    /// is cheap, if they are already ordered.
+    pub(crate) fn sort_kids(&mut self, v: usize) {
+        let edges = &mut self.vertices.get_mut(v).unwrap().edges;
+        if edges
+            .iter()
+            .zip(edges.iter().skip(1))
+            .all(|(x, y)| x.0 < y.0)
+        {
+            return;
+        }
+        let mut pairs: Vec<(Label, usize)> = edges.iter().map(|(a, to)| (*a, *to)).collect();
+        pairs.sort_unstable();
+        edges.clear();
+        for (a, to) in pairs {
+            edges.insert(a, to);
+        }
+    }
+
+    /// If the vertex with data has just moved from the `before` branch
+    /// to another one, move its store too.
+    fn move_store(&mut self, v: usize, before: usize) {
+        let vtx = self.vertices.get(v).unwrap();
+        let after = vtx.branch;
+        if after != before && vtx.persistence == Persistence::Stored {
+            let s = self.stores.get_mut(before).unwrap();
+            *s = s.saturating_sub(1);
+            *self.stores.get_mut(after).unwrap() += 1;
+        }
+    }
+
+    /// Look at the data of a vertex, without submitting it
+    /// to garbage collection, as [`Sodg::data`] does.
+    pub(crate) fn peek(&self, v: usize) -> Option<&Hex> {
+        self.vertices
+            .get(v)
+            .filter(|vtx| vtx.persistence != Persistence::Empty)
+            .map(|vtx| &vtx.data)
+    }
+
+    /// Add the vertex, but only if it is not in the graph yet.
+    pub(crate) fn add_if_absent(&mut self, v: usize) {
+        if self.vertices.get(v).unwrap().branch == BRANCH_NONE {
+            self.add(v);
+        }
+    }
+
+    /// Add the vertex, if it is not in the graph yet, making sure
+    /// it fits into the capacity of the graph.
+    ///
+    /// This is what parsers must use for IDs that come from the outside.
+    pub(crate) fn try_add_if_absent(&mut self, v: usize) -> Result<()> {
+        self.fits(v)?;
+        self.add_if_absent(v);
+        Ok(())
+    }
+
+    /// Make sure the vertex fits into the capacity of the graph.
+    pub(crate) fn fits(&self, v: usize) -> Result<()> {
+        let cap = self.vertices.capacity();
+        if v >= cap {
+            return Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"));
+        }
+        Ok(())
+    }
+
+    /// Erase the vertex: remove its edges and data, and exclude it from its branch.
+    ///
+    /// Edges that point to the vertex from other vertices are not touched,
+    /// it's the responsibility of the caller to take care of them.
+    pub(crate) fn erase(&mut self, v: usize) {
+        let kids = self.wipe(v);
+        self.release(kids);
+    }
+
+    /// Erase the vertex, the same way `erase()` does, but without
+    /// releasing its kids, returning them instead.
+    pub(crate) fn wipe(&mut self, v: usize) -> Vec<usize> {
+        self.forget(v);
+        let vtx = self.vertices.get_mut(v).unwrap();
+        let branch = vtx.branch;
+        if vtx.persistence == Persistence::Stored {
+            let s = self.stores.get_mut(branch).unwrap();
+            *s = s.saturating_sub(1);
+        }
+        vtx.branch = BRANCH_NONE;
+        vtx.data = Hex::empty();
+        vtx.persistence = Persistence::Empty;
+        let targets: Vec<usize> = vtx.edges.iter().map(|(_, to)| *to).collect();
+        vtx.edges.clear();
+        if branch > BRANCH_STATIC {
+            let members = self.branches.get_mut(branch).unwrap();
+            let rest: Vec<usize> = members.into_iter().filter(|m| *m != v).collect();
+            *members = microstack::Stack::from_vec(rest);
+        }
+        #[cfg(debug_assertions)]
+        trace!("#erase: vertex ν{v} erased");
+        targets
+    }
+
+    /// Remove everything the side tables know about the vertex and its
+    /// edges, telling the watchers that the edges are gone.
+    ///
+    /// The vertex itself is not touched.
+    fn forget(&mut self, v: usize) {
+        if let Some(refs) = &mut self.refs {
+            refs.remove(&v);
+        }
+        let kids: Vec<(Label, usize)> = self
+            .vertices
+            .get(v)
+            .unwrap()
+            .edges
+            .iter()
+            .map(|(a, to)| (*a, *to))
+            .collect();
+        for (a, to) in kids {
+            if let Some(idx) = &mut self.index {
+                idx.remove(&(v, a));
+            }
+            self.attrs.remove(&(v, a));
+            self.notify(v, VertexEventKind::OutgoingEdgeRemoved, to);
+            self.notify(to, VertexEventKind::IncomingEdgeRemoved, v);
+        }
+        if let Some(name) = self.names.remove(&v) {
+            self.named.remove(&name);
+        }
+    }
+}
 
 #[test]
 fn adds_simple_vertex() {
@@ -435,9 +798,129 @@ fn gets_kid_from_absent_vertex() {
     assert!(g.kid(0, Label::from_str("hello").unwrap()).is_none());
 }
 
+#[test]
+fn reads_data_put_before_binding() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.put(0, &Hex::from(42));
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    assert_eq!(42, g.data(0).unwrap().to_i64().unwrap());
+    assert_eq!(0, g.len());
+}
+
+#[test]
+fn forgets_collected_vertices() {
+    use std::sync::{Arc, Mutex};
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.put(1, &Hex::from(42));
+    g.set_vertex_label(0, "root").unwrap();
+    g.set_edge_attr(0, Label::Alpha(0), "color", "red").unwrap();
+    g.build_index();
+    let seen = Arc::new(Mutex::new(vec![]));
+    let s = seen.clone();
+    g.watch_vertex(0, move |e| s.lock().unwrap().push(e.kind));
+    g.data(1);
+    assert_eq!(0, g.len());
+    assert!(g.vertex_by_label("root").is_none());
+    assert!(g.vertex_label(0).is_none());
+    assert!(g.kid_indexed(0, Label::Alpha(0)).is_none());
+    assert!(g.attrs.is_empty());
+    assert_eq!(
+        vec![VertexEventKind::OutgoingEdgeRemoved],
+        *seen.lock().unwrap()
+    );
+}
+
 #[test]
 fn adds_twice() {
     let mut g: Sodg<16> = Sodg::empty(256);
     g.add(0);
     g.add(0);
 }
+
+#[test]
+fn lists_kids_in_label_order() {
+    let mut first: Sodg<16> = Sodg::empty(256);
+    let mut second: Sodg<16> = Sodg::empty(256);
+    let labels = [
+        Label::from_str("b").unwrap(),
+        Label::Alpha(1),
+        Label::from_str("ab").unwrap(),
+        Label::Alpha(0),
+    ];
+    for g in [&mut first, &mut second] {
+        for v in 0..5 {
+            g.add(v);
+        }
+    }
+    for (i, a) in labels.iter().enumerate() {
+        first.bind(0, i + 1, *a);
+    }
+    for (i, a) in labels.iter().enumerate().rev() {
+        second.bind(0, i + 1, *a);
+    }
+    let kids: Vec<_> = first.kids(0).collect();
+    assert_eq!(kids, second.kids(0).collect::<Vec<_>>());
+    assert_eq!(
+        "α0,α1,ab,b",
+        kids.iter()
+            .map(|(a, _)| a.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    );
+}
+
+#[test]
+fn inserts_vertex_on_edge() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.put(1, &Hex::from(42));
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    let mid = g
+        .insert_vertex_on_edge(
+            0,
+            Label::from_str("foo").unwrap(),
+            Label::from_str("bar").unwrap(),
+        )
+        .unwrap();
+    assert_eq!(3, g.len());
+    assert_eq!(Some(mid), g.kid(0, Label::from_str("foo").unwrap()));
+    assert_eq!(Some(1), g.kid(mid, Label::from_str("bar").unwrap()));
+    assert_eq!(0, g.kids(1).count());
+    assert_eq!(42, g.data(1).unwrap().to_i64().unwrap());
+    assert!(g
+        .insert_vertex_on_edge(1, Label::Alpha(0), Label::Alpha(1))
+        .is_err());
+}
+
+#[test]
+fn maps_all_labels() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(1, 2, Label::from_str("foo").unwrap());
+    g.bind(0, 2, Label::from_str("x").unwrap());
+    let before = g.to_dot();
+    g.map_labels(ToString::to_string).unwrap();
+    assert_eq!(before, g.to_dot());
+    g.map_labels(|a| {
+        if a == "foo" {
+            "bar".to_string()
+        } else {
+            a.to_string()
+        }
+    })
+    .unwrap();
+    assert_eq!(Some(2), g.kid(1, Label::from_str("bar").unwrap()));
+    assert!(!g.to_dot().contains("foo"));
+    assert!(g.to_dot().contains("bar"));
+    assert!(g.map_labels(|_| "same".to_string()).is_err());
+    assert_eq!(Some(2), g.kid(0, Label::from_str("x").unwrap()));
+}
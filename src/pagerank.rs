@@ -0,0 +1,139 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Rank all vertices with the iterative `PageRank` algorithm, treating
+    /// edges as links.
+    ///
+    /// The rank of a vertex with no outgoing edges (a "dangling" one) is
+    /// evenly spread among all vertices on every iteration, thus the
+    /// sum of all ranks always stays equal to 1.0. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let ranks = g.pagerank(0.85, 50);
+    /// assert!(ranks[&1] > ranks[&0]);
+    /// ```
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn pagerank(&self, damping: f64, iterations: usize) -> HashMap<usize, f64> {
+        let keys = self.keys();
+        if keys.is_empty() {
+            return HashMap::new();
+        }
+        let total = keys.len() as f64;
+        let mut ranks: HashMap<usize, f64> = keys.iter().map(|v| (*v, 1.0 / total)).collect();
+        for _ in 0..iterations {
+            let dangling: f64 = keys
+                .iter()
+                .filter(|v| self.live_kids(**v).next().is_none())
+                .map(|v| ranks[v])
+                .sum();
+            let base = damping.mul_add(dangling / total, (1.0 - damping) / total);
+            let mut next: HashMap<usize, f64> = keys.iter().map(|v| (*v, base)).collect();
+            for v in &keys {
+                let out = self.live_kids(*v).count();
+                if out == 0 {
+                    continue;
+                }
+                let share = damping * ranks[v] / out as f64;
+                for (_, to) in self.live_kids(*v) {
+                    *next.entry(*to).or_default() += share;
+                }
+            }
+            ranks = next;
+        }
+        ranks
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn ranks_hub_highest() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    for v in 1..5 {
+        g.bind(v, 0, Label::Alpha(0));
+    }
+    let ranks = g.pagerank(0.85, 50);
+    for v in 1..5 {
+        assert!(ranks[&0] > ranks[&v]);
+    }
+    assert!((ranks.values().sum::<f64>() - 1.0).abs() < 1e-9);
+}
+
+#[test]
+fn ranks_cycle_evenly() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    for v in 0..4 {
+        g.bind(v, (v + 1) % 4, Label::Alpha(0));
+    }
+    let ranks = g.pagerank(0.85, 50);
+    for v in 0..4 {
+        assert!((ranks[&v] - 0.25).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn converges_to_known_ranks() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(2, 0, Label::Alpha(0));
+    let ranks = g.pagerank(0.85, 50);
+    assert!((ranks[&0] - 0.3878).abs() < 0.001);
+    assert!((ranks[&1] - 0.2148).abs() < 0.001);
+    assert!((ranks[&2] - 0.3974).abs() < 0.001);
+}
+
+#[test]
+fn ignores_collected_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let ranks = g.pagerank(0.85, 50);
+    assert_eq!(2, ranks.len());
+    assert!((ranks.values().sum::<f64>() - 1.0).abs() < 1e-9);
+}
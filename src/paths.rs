@@ -0,0 +1,224 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{SimplePaths, Sodg};
+use std::collections::HashSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Find all simple paths (without repeated vertices) from one vertex
+    /// to another, lazily, one path at a time.
+    ///
+    /// Paths are found by a depth-first search, which visits kids in the
+    /// order of the labels of their edges. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 2, Label::Alpha(1));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// let paths: Vec<Vec<usize>> = g.all_simple_paths(0, 2).collect();
+    /// assert_eq!(vec![vec![0, 1, 2], vec![0, 2]], paths);
+    /// ```
+    #[must_use]
+    pub fn all_simple_paths(&self, from: usize, to: usize) -> SimplePaths<'_, N> {
+        let mut paths = SimplePaths {
+            g: self,
+            to,
+            stack: vec![],
+            visited: HashSet::new(),
+            pending: None,
+        };
+        if self.alive(from) {
+            if from == to {
+                paths.pending = Some(vec![from]);
+            } else {
+                paths.stack.push((from, paths.targets(from)));
+                paths.visited.insert(from);
+            }
+        }
+        paths
+    }
+
+    /// Check whether there is a directed path from one vertex to another.
+    ///
+    /// A vertex is always reachable from itself. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// assert!(g.is_reachable(0, 1));
+    /// assert!(!g.is_reachable(1, 0));
+    /// ```
+    #[must_use]
+    pub fn is_reachable(&self, from: usize, to: usize) -> bool {
+        if !self.alive(from) {
+            return false;
+        }
+        let mut seen = HashSet::from([from]);
+        let mut todo = vec![from];
+        while let Some(v) = todo.pop() {
+            if v == to {
+                return true;
+            }
+            for (_, k) in self.live_kids(v) {
+                if seen.insert(*k) {
+                    todo.push(*k);
+                }
+            }
+        }
+        false
+    }
+}
+
+impl<const N: usize> SimplePaths<'_, N> {
+    /// Distinct kids of a vertex, in reverse order, ready to be popped.
+    fn targets(&self, v: usize) -> Vec<usize> {
+        let mut seen = HashSet::new();
+        let mut kids: Vec<usize> = self
+            .g
+            .live_kids(v)
+            .map(|(_, to)| *to)
+            .filter(|to| seen.insert(*to))
+            .collect();
+        kids.reverse();
+        kids
+    }
+}
+
+impl<const N: usize> Iterator for SimplePaths<'_, N> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if let Some(p) = self.pending.take() {
+            return Some(p);
+        }
+        while let Some((_, kids)) = self.stack.last_mut() {
+            let Some(k) = kids.pop() else {
+                if let Some((v, _)) = self.stack.pop() {
+                    self.visited.remove(&v);
+                }
+                continue;
+            };
+            if self.visited.contains(&k) {
+                continue;
+            }
+            if k == self.to {
+                let mut path: Vec<usize> = self.stack.iter().map(|(v, _)| *v).collect();
+                path.push(k);
+                return Some(path);
+            }
+            let kids = self.targets(k);
+            self.visited.insert(k);
+            self.stack.push((k, kids));
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+fn ladder(n: usize) -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..=n {
+        g.add(v);
+    }
+    for v in 0..n {
+        g.bind(v, v + 1, Label::Alpha(0));
+        g.bind(v, v + 1, Label::Alpha(1));
+        if v + 2 <= n {
+            g.bind(v, v + 2, Label::Alpha(2));
+        }
+    }
+    g
+}
+
+#[test]
+fn counts_all_paths() {
+    let g = ladder(6);
+    assert_eq!(13, g.all_simple_paths(0, 6).count());
+    assert_eq!(vec![vec![3]], g.all_simple_paths(3, 3).collect::<Vec<_>>());
+    assert_eq!(0, g.all_simple_paths(6, 0).count());
+}
+
+#[test]
+fn stops_early() {
+    let g = ladder(12);
+    let mut paths = g.all_simple_paths(0, 12);
+    let first: Vec<Vec<usize>> = paths.by_ref().take(5).collect();
+    assert_eq!(5, first.len());
+    assert_eq!((0..=12).collect::<Vec<usize>>(), first[0]);
+    assert!(paths.stack.len() <= 13);
+}
+
+#[test]
+fn skips_cycles() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 0, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(1));
+    assert_eq!(
+        vec![vec![0, 1, 2]],
+        g.all_simple_paths(0, 2).collect::<Vec<_>>()
+    );
+}
+
+#[cfg(test)]
+fn collected() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    g
+}
+
+#[test]
+fn does_not_reach_collected_vertices() {
+    let g = collected();
+    assert!(g.is_reachable(0, 1));
+    assert!(!g.is_reachable(0, 3));
+    assert!(!g.is_reachable(0, 2));
+}
+
+#[test]
+fn skips_collected_vertices() {
+    let g = collected();
+    assert_eq!(
+        vec![vec![0, 1]],
+        g.all_simple_paths(0, 1).collect::<Vec<_>>()
+    );
+    assert_eq!(0, g.all_simple_paths(0, 3).count());
+}
@@ -0,0 +1,97 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Persistence, Sodg};
+#[cfg(debug_assertions)]
+use log::trace;
+
+impl<const N: usize> Sodg<N> {
+    /// Remove all vertices that have no data, no outgoing edges,
+    /// and no incoming edges, returning the number of vertices removed.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.put(1, &Hex::from(42));
+    /// assert_eq!(1, g.prune_empty_vertices());
+    /// assert_eq!(1, g.len());
+    /// ```
+    pub fn prune_empty_vertices(&mut self) -> usize {
+        self.prune(true)
+    }
+
+    /// Remove all vertices that have no outgoing edges and no incoming
+    /// edges, no matter whether they have data or not, returning
+    /// the number of vertices removed.
+    pub fn prune_isolated(&mut self) -> usize {
+        self.prune(false)
+    }
+
+    /// Remove isolated vertices, maybe keeping the ones with data.
+    fn prune(&mut self, keep_data: bool) -> usize {
+        let degrees = self.in_degrees();
+        let victims: Vec<usize> = self
+            .vertices
+            .iter()
+            .filter(|(v, vtx)| self.alive(*v) && vtx.edges.is_empty() && degrees[v] == 0)
+            .filter(|(_, vtx)| !keep_data || vtx.persistence == Persistence::Empty)
+            .map(|(v, _)| v)
+            .collect();
+        for v in &victims {
+            self.erase(*v);
+        }
+        #[cfg(debug_assertions)]
+        trace!("#prune: {} isolated vertices removed", victims.len());
+        victims.len()
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn prunes_isolated_empty_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..8 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(3, 4, Label::Alpha(0));
+    assert_eq!(3, g.prune_empty_vertices());
+    assert_eq!(5, g.len());
+    assert_eq!(0, g.prune_empty_vertices());
+}
+
+#[test]
+fn keeps_isolated_vertices_with_data() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.put(1, &Hex::from(42));
+    assert_eq!(1, g.prune_empty_vertices());
+    assert_eq!(vec![1], g.keys());
+    assert_eq!(1, g.prune_isolated());
+    assert!(g.is_empty());
+}
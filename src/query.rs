@@ -0,0 +1,167 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Sodg};
+use anyhow::{anyhow, Context, Result};
+use regex::Regex;
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::LazyLock;
+
+/// A single condition of a query.
+enum Cond {
+    HasData,
+    HasEdge(Label),
+    Not(Box<Self>),
+    Cmp(String, String, usize),
+}
+
+impl<const N: usize> Sodg<N> {
+    /// Find all vertices that match the filter, written in a mini
+    /// SQL-like language, sorted by their IDs.
+    ///
+    /// The filter consists of conditions, joined by `AND` and `OR` (the
+    /// former binds stronger than the latter), each of which may be negated
+    /// by `NOT`. A condition is either `has_data`, or `has_edge(label)`, or
+    /// a comparison of `data_len`, `in_degree`, or `out_degree` with
+    /// a number, using `==`, `!=`, `<`, `<=`, `>`, or `>=`. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 2, Label::Alpha(1));
+    /// g.put(0, &Hex::from(42));
+    /// assert_eq!(vec![0], g.query("data_len == 8 AND out_degree > 1").unwrap());
+    /// assert_eq!(vec![1, 2], g.query("NOT has_data").unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the filter can't be parsed, an error will be returned.
+    pub fn query(&self, filter: &str) -> Result<Vec<usize>> {
+        static OR: LazyLock<Regex> = LazyLock::new(|| Regex::new("\\s+OR\\s+").unwrap());
+        static AND: LazyLock<Regex> = LazyLock::new(|| Regex::new("\\s+AND\\s+").unwrap());
+        let mut expr = vec![];
+        for alt in OR.split(filter.trim()) {
+            let mut all = vec![];
+            for term in AND.split(alt) {
+                all.push(
+                    Self::parse_cond(term).with_context(|| format!("Can't parse '{filter}'"))?,
+                );
+            }
+            expr.push(all);
+        }
+        let degrees = self.in_degrees();
+        Ok(self
+            .keys()
+            .into_iter()
+            .filter(|v| {
+                expr.iter()
+                    .any(|all| all.iter().all(|c| self.check(*v, c, &degrees)))
+            })
+            .collect())
+    }
+
+    /// Parse a single condition.
+    fn parse_cond(term: &str) -> Result<Cond> {
+        static CMP: LazyLock<Regex> = LazyLock::new(|| {
+            Regex::new("^(data_len|in_degree|out_degree) *(==|!=|<=|>=|<|>) *([0-9]+)$").unwrap()
+        });
+        static EDGE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^has_edge *\\( *([^ )]+) *\\)$").unwrap());
+        let t = term.trim();
+        if let Some(rest) = t.strip_prefix("NOT ") {
+            return Ok(Cond::Not(Box::new(Self::parse_cond(rest)?)));
+        }
+        if t == "has_data" {
+            return Ok(Cond::HasData);
+        }
+        if let Some(cap) = EDGE.captures(t) {
+            return Ok(Cond::HasEdge(Label::from_str(&cap[1])?));
+        }
+        let cap = CMP
+            .captures(t)
+            .ok_or_else(|| anyhow!("Unknown condition '{t}'"))?;
+        Ok(Cond::Cmp(
+            cap[1].to_string(),
+            cap[2].to_string(),
+            usize::from_str(&cap[3])?,
+        ))
+    }
+
+    /// Check one condition against a vertex.
+    fn check(&self, v: usize, c: &Cond, degrees: &HashMap<usize, usize>) -> bool {
+        match c {
+            Cond::HasData => self.peek(v).is_some(),
+            Cond::HasEdge(a) => self.kid(v, *a).is_some(),
+            Cond::Not(c) => !self.check(v, c, degrees),
+            Cond::Cmp(metric, op, expected) => {
+                let actual = match metric.as_str() {
+                    "data_len" => self.peek(v).map_or(0, Hex::len),
+                    "in_degree" => degrees[&v],
+                    _ => self.out_degree(v),
+                };
+                match op.as_str() {
+                    "==" => actual == *expected,
+                    "!=" => actual != *expected,
+                    "<" => actual < *expected,
+                    "<=" => actual <= *expected,
+                    ">" => actual > *expected,
+                    _ => actual >= *expected,
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn queries_by_degrees_and_data() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 2, Label::from_str("foo").unwrap());
+    g.put(3, &Hex::from_str_bytes("hi"));
+    assert_eq!(vec![2], g.query("in_degree >= 2").unwrap());
+    assert_eq!(vec![1], g.query("has_edge(foo)").unwrap());
+    assert_eq!(
+        vec![0, 3],
+        g.query("out_degree > 1 OR data_len == 2").unwrap()
+    );
+    assert_eq!(
+        vec![3],
+        g.query("has_data AND NOT has_edge(foo) AND in_degree == 0")
+            .unwrap()
+    );
+}
+
+#[test]
+fn rejects_broken_query() {
+    let g: Sodg<16> = Sodg::empty(256);
+    assert!(g.query("size > 3").is_err());
+    assert!(g.query("in_degree >> 3").is_err());
+    assert!(g.query("").is_err());
+}
@@ -0,0 +1,135 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Sodg, BRANCH_STATIC, MAX_BRANCH_SIZE};
+use rand::distributions::Alphanumeric;
+use rand::rngs::SmallRng;
+use rand::{Rng, SeedableRng};
+
+impl<const N: usize> Sodg<N> {
+    /// Make a random graph, which is always the same for the same `seed`.
+    ///
+    /// Vertices are numbered from zero. Each edge has a random label of four
+    /// alphanumeric chars, about half of the vertices get random data.
+    /// The graph has about `vertices * avg_out_degree` edges, but an edge
+    /// is not added if its vertex already has `N` edges or if it would
+    /// make a branch bigger than it may be. That's why in large and dense
+    /// graphs the average out-degree may be lower than requested.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::Sodg;
+    /// let g : Sodg<16> = Sodg::random(10, 1.5, 42);
+    /// assert_eq!(10, g.len());
+    /// assert!(g.validate().is_ok());
+    /// ```
+    ///
+    /// This function is available only with the `rand` feature.
+    #[must_use]
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn random(vertices: usize, avg_out_degree: f64, seed: u64) -> Self {
+        let mut rng = SmallRng::seed_from_u64(seed);
+        let mut g = Self::empty(vertices.max(1));
+        for v in 0..vertices {
+            g.add(v);
+            if rng.gen_bool(0.5) {
+                g.put(v, &Hex::from_vec(rng.gen::<[u8; 4]>().to_vec()));
+            }
+        }
+        if vertices < 2 {
+            return g;
+        }
+        let total = (vertices as f64 * avg_out_degree).round() as usize;
+        let mut edges = 0;
+        for _ in 0..total * 10 {
+            if edges == total {
+                break;
+            }
+            let v1 = rng.gen_range(0..vertices);
+            let v2 = rng.gen_range(0..vertices);
+            if v1 == v2 || g.out_degree(v1) == N || !g.can_bind(v1, v2) {
+                continue;
+            }
+            let a = Self::random_label(&mut rng);
+            if g.kid(v1, a).is_some() {
+                continue;
+            }
+            g.bind(v1, v2, a);
+            edges += 1;
+        }
+        g
+    }
+
+    /// Make a random label of four alphanumeric chars.
+    fn random_label(rng: &mut SmallRng) -> Label {
+        let mut chars = [' '; 8];
+        for c in chars.iter_mut().take(4) {
+            *c = char::from(rng.sample(Alphanumeric));
+        }
+        Label::Str(chars)
+    }
+
+    /// Can these two vertices be bound without overflowing a branch?
+    fn can_bind(&self, v1: usize, v2: usize) -> bool {
+        let ours = self.vertices.get(v1).unwrap().branch;
+        let theirs = self.vertices.get(v2).unwrap().branch;
+        match (ours == BRANCH_STATIC, theirs == BRANCH_STATIC) {
+            (true, true) => self.branches.iter().any(|(_, b)| b.is_empty()),
+            (true, false) => self.branches.get(theirs).unwrap().len() < MAX_BRANCH_SIZE,
+            (false, true) => self.branches.get(ours).unwrap().len() < MAX_BRANCH_SIZE,
+            (false, false) => true,
+        }
+    }
+}
+
+#[test]
+fn makes_same_graph_from_same_seed() {
+    let first: Sodg<16> = Sodg::random(16, 2.0, 7);
+    let second: Sodg<16> = Sodg::random(16, 2.0, 7);
+    first.assert_structurally_equal(&second);
+    assert!(first.validate().is_ok());
+}
+
+#[test]
+fn makes_different_graphs_from_different_seeds() {
+    let first: Sodg<16> = Sodg::random(16, 2.0, 7);
+    let second: Sodg<16> = Sodg::random(16, 2.0, 8);
+    assert_ne!(first.to_dot(), second.to_dot());
+}
+
+#[test]
+#[allow(clippy::cast_precision_loss)]
+fn keeps_average_out_degree() {
+    let g: Sodg<16> = Sodg::random(16, 3.0, 42);
+    let edges: usize = g.keys().into_iter().map(|v| g.out_degree(v)).sum();
+    let avg = edges as f64 / g.len() as f64;
+    assert!((avg - 3.0).abs() < 0.5, "average out-degree is {avg}");
+}
+
+#[test]
+fn survives_large_graph() {
+    let g: Sodg<16> = Sodg::random(1000, 2.0, 1);
+    assert_eq!(1000, g.len());
+    assert!(g.validate().is_ok());
+}
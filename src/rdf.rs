@@ -0,0 +1,162 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Sodg};
+use std::fmt::Write;
+
+impl<const N: usize> Sodg<N> {
+    /// Print the graph in [Turtle](https://www.w3.org/TR/turtle/) format,
+    /// as a set of RDF triples.
+    ///
+    /// Each vertex becomes a subject `<base_uri/v/ID>`, each edge becomes
+    /// a triple with the predicate `<base_uri/a/LABEL>`, and the data of a
+    /// vertex becomes a literal of the predicate `<base_uri/data>`: a string,
+    /// if the data is a printable UTF-8 text, or `xsd:hexBinary` otherwise.
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// g.put(1, &Hex::from_str_bytes("hi"));
+    /// assert_eq!(
+    ///     "<http://x/v/0> <http://x/a/foo> <http://x/v/1> .\n\
+    ///     <http://x/v/1> <http://x/data> \"hi\" .\n",
+    ///     g.to_turtle("http://x")
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_turtle(&self, base_uri: &str) -> String {
+        let base = base_uri.trim_end_matches('/');
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut ttl = String::new();
+        for v in keys {
+            for (a, to) in self.kids(v) {
+                writeln!(
+                    ttl,
+                    "<{base}/v/{v}> <{base}/a/{}> <{base}/v/{to}> .",
+                    Self::rdf_label(*a)
+                )
+                .unwrap();
+            }
+            if let Some(d) = self.peek(v) {
+                writeln!(
+                    ttl,
+                    "<{base}/v/{v}> <{base}/data> {} .",
+                    Self::rdf_literal(d)
+                )
+                .unwrap();
+            }
+        }
+        ttl
+    }
+
+    /// Print the label, percent-encoding everything that may break an IRI.
+    fn rdf_label(a: Label) -> String {
+        let mut out = String::new();
+        for c in a.to_string().chars() {
+            if c.is_alphanumeric() || "-._~".contains(c) {
+                out.push(c);
+            } else {
+                let mut buf = [0; 4];
+                for b in c.encode_utf8(&mut buf).bytes() {
+                    write!(out, "%{b:02X}").unwrap();
+                }
+            }
+        }
+        out
+    }
+
+    /// Print the data as a Turtle literal.
+    fn rdf_literal(d: &Hex) -> String {
+        match d.to_utf8() {
+            Ok(txt) if !txt.chars().any(|c| c.is_control() && !"\t\n\r".contains(c)) => {
+                let escaped = txt
+                    .replace('\\', "\\\\")
+                    .replace('"', "\\\"")
+                    .replace('\n', "\\n")
+                    .replace('\r', "\\r")
+                    .replace('\t', "\\t");
+                format!("\"{escaped}\"")
+            }
+            _ => format!(
+                "\"{}\"^^<http://www.w3.org/2001/XMLSchema#hexBinary>",
+                hex::encode_upper(d.bytes())
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn prints_known_graph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(0, 2, Label::from_str("a/b").unwrap());
+    g.put(2, &Hex::from(42));
+    assert_eq!(
+        "<urn:g/v/0> <urn:g/a/a%2Fb> <urn:g/v/2> .\n\
+        <urn:g/v/0> <urn:g/a/foo> <urn:g/v/1> .\n\
+        <urn:g/v/2> <urn:g/data> \"000000000000002A\"^^<http://www.w3.org/2001/XMLSchema#hexBinary> .\n",
+        g.to_turtle("urn:g/")
+    );
+}
+
+#[test]
+fn prints_unicode_literal() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.put(0, &Hex::from_str_bytes("привет \"мир\""));
+    assert_eq!(
+        "<urn:g/v/0> <urn:g/data> \"привет \\\"мир\\\"\" .\n",
+        g.to_turtle("urn:g")
+    );
+}
+
+#[test]
+fn prints_parseable_triples() {
+    let triple = regex::Regex::new(
+        "^<[^<>\"{}|^`\\\\ ]+> <[^<>\"{}|^`\\\\ ]+> (<[^<>\"{}|^`\\\\ ]+>|\"([^\"\\\\\n\r]|\\\\[tnr\"\\\\])*\"(\\^\\^<[^<> ]+>)?) \\.$",
+    )
+    .unwrap();
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("a b").unwrap());
+    g.bind(0, 2, Label::Alpha(0));
+    g.bind(2, 3, Label::from_str("ρ").unwrap());
+    g.put(1, &Hex::from_str_bytes("line\none \"quoted\" \\ back"));
+    g.put(3, &Hex::from(-1));
+    let ttl = g.to_turtle("http://example.com/g");
+    assert_eq!(5, ttl.lines().count());
+    for line in ttl.lines() {
+        assert!(triple.is_match(line), "Not a triple: {line}");
+    }
+}
@@ -0,0 +1,195 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{ReachabilityMatrix, Sodg};
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Calculate the transitive closure of the graph, in order to
+    /// answer reachability questions in constant time.
+    ///
+    /// The matrix is a snapshot: it doesn't see the changes made to the
+    /// graph later, unless they are reported by
+    /// [`ReachabilityMatrix::add_edge`]. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let m = g.precompute_reachability();
+    /// assert!(m.is_reachable(0, 1));
+    /// assert!(!m.is_reachable(1, 0));
+    /// ```
+    #[must_use]
+    pub fn precompute_reachability(&self) -> ReachabilityMatrix {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let index: HashMap<usize, usize> = keys.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+        let mut m = ReachabilityMatrix {
+            bits: vec![0; (keys.len() * keys.len()).div_ceil(8)],
+            index,
+        };
+        for (i, v) in keys.iter().enumerate() {
+            let mut todo = vec![*v];
+            m.set(i, i);
+            while let Some(u) = todo.pop() {
+                for (_, k) in self.kids(u) {
+                    let Some(&j) = m.index.get(k) else {
+                        continue;
+                    };
+                    if !m.get(i, j) {
+                        m.set(i, j);
+                        todo.push(*k);
+                    }
+                }
+            }
+        }
+        m
+    }
+}
+
+impl ReachabilityMatrix {
+    /// Check whether there is a directed path from one vertex to another.
+    ///
+    /// A vertex is always reachable from itself, while vertices unknown
+    /// to the matrix are not reachable from anywhere.
+    #[must_use]
+    pub fn is_reachable(&self, from: usize, to: usize) -> bool {
+        match (self.index.get(&from), self.index.get(&to)) {
+            (Some(i), Some(j)) => self.get(*i, *j),
+            _ => false,
+        }
+    }
+
+    /// Update the matrix after an edge was added to the graph,
+    /// without calculating everything again.
+    ///
+    /// # Errors
+    ///
+    /// If any of the vertices is unknown to the matrix, an error
+    /// will be returned.
+    pub fn add_edge(&mut self, from: usize, to: usize) -> Result<()> {
+        let i = *self
+            .index
+            .get(&from)
+            .ok_or_else(|| anyhow!("Vertex ν{from} is not in the matrix"))?;
+        let j = *self
+            .index
+            .get(&to)
+            .ok_or_else(|| anyhow!("Vertex ν{to} is not in the matrix"))?;
+        let total = self.index.len();
+        let targets: Vec<usize> = (0..total).filter(|y| self.get(j, *y)).collect();
+        for x in 0..total {
+            if self.get(x, i) {
+                for y in &targets {
+                    self.set(x, *y);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// How many bytes are occupied by the bits of the matrix.
+    #[must_use]
+    pub const fn byte_size(&self) -> usize {
+        self.bits.len()
+    }
+
+    /// Get the bit of the pair.
+    fn get(&self, i: usize, j: usize) -> bool {
+        let pos = i * self.index.len() + j;
+        self.bits[pos / 8] & (1 << (pos % 8)) != 0
+    }
+
+    /// Set the bit of the pair.
+    fn set(&mut self, i: usize, j: usize) {
+        let pos = i * self.index.len() + j;
+        self.bits[pos / 8] |= 1 << (pos % 8);
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+use crate::Hex;
+
+#[cfg(test)]
+fn diamond() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(4, 5, Label::Alpha(0));
+    g.bind(5, 4, Label::Alpha(0));
+    g
+}
+
+#[test]
+fn agrees_with_search() {
+    let g = diamond();
+    let m = g.precompute_reachability();
+    for from in 0..6 {
+        for to in 0..6 {
+            assert_eq!(g.is_reachable(from, to), m.is_reachable(from, to));
+        }
+    }
+    assert!(!m.is_reachable(0, 42));
+    assert_eq!(36 / 8 + 1, m.byte_size());
+}
+
+#[test]
+fn adds_edge_incrementally() {
+    let mut g = diamond();
+    let mut m = g.precompute_reachability();
+    assert!(!m.is_reachable(1, 5));
+    g.bind(3, 4, Label::Alpha(0));
+    m.add_edge(3, 4).unwrap();
+    for from in 0..6 {
+        for to in 0..6 {
+            assert_eq!(g.is_reachable(from, to), m.is_reachable(from, to));
+        }
+    }
+    assert!(m.add_edge(3, 42).is_err());
+}
+
+#[test]
+fn skips_collected_kids() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let m = g.precompute_reachability();
+    for v in g.keys() {
+        assert!(m.is_reachable(v, v));
+    }
+}
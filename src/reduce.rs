@@ -0,0 +1,168 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Persistence, Sodg, BRANCH_NONE};
+#[cfg(debug_assertions)]
+use log::trace;
+
+impl<const N: usize> Sodg<N> {
+    /// Collapse the graph, merging pairs of vertices that the
+    /// `should_merge` predicate selects.
+    ///
+    /// All ordered pairs of alive vertices are visited, in ascending order of their IDs.
+    /// When the predicate returns `true` for `(left, right)`, the `right` vertex
+    /// is merged into the `left` one: edges pointing to `right` are redirected
+    /// to `left`, the kids of `right` become the kids of `left` (unless `left`
+    /// already has a kid with the same label), and the data of `right` moves
+    /// to `left`, unless `left` has its own. Edges that would turn into loops
+    /// are dropped. The predicate is never called for vertices already merged.
+    /// The function returns the total number of merges made.
+    ///
+    /// For example, this is how all kids without data and without
+    /// their own kids may be merged into their parents:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let total = g.reduce(|g, left, right| {
+    ///     g.kids(left).any(|(_, to)| *to == right) && g.kids(right).count() == 0
+    /// });
+    /// assert_eq!(1, total);
+    /// assert_eq!(1, g.len());
+    /// ```
+    pub fn reduce<F: Fn(&Self, usize, usize) -> bool>(&mut self, should_merge: F) -> usize {
+        let all = self.keys();
+        let mut total = 0;
+        for left in &all {
+            for right in &all {
+                if left == right || !self.alive(*left) || !self.alive(*right) {
+                    continue;
+                }
+                if should_merge(self, *left, *right) {
+                    self.absorb(*left, *right);
+                    total += 1;
+                }
+            }
+        }
+        #[cfg(debug_assertions)]
+        trace!("#reduce: {total} vertices merged, {} left", self.len());
+        total
+    }
+
+    /// Is this vertex in the graph?
+    pub(crate) fn alive(&self, v: usize) -> bool {
+        self.vertices
+            .get(v)
+            .is_some_and(|vtx| vtx.branch != BRANCH_NONE)
+    }
+
+    /// Merge the `right` vertex into the `left` one and erase the `right` one.
+    pub(crate) fn absorb(&mut self, left: usize, right: usize) {
+        for v in self.keys() {
+            if v == right {
+                continue;
+            }
+            let vtx = self.vertices.get_mut(v).unwrap();
+            let incoming: Vec<_> = vtx
+                .edges
+                .iter()
+                .filter(|(_, to)| **to == right)
+                .map(|(a, _)| *a)
+                .collect();
+            for a in incoming {
+                if v == left {
+                    vtx.edges.remove(&a);
+                } else {
+                    vtx.edges.insert(a, left);
+                }
+            }
+        }
+        self.sort_kids(left);
+        self.reindex();
+        self.recount();
+        let vtx = self.vertices.get(right).unwrap().clone();
+        for (a, to) in &vtx.edges {
+            if *to == left || *to == right || self.kid(left, *a).is_some() {
+                continue;
+            }
+            self.bind(left, *to, *a);
+        }
+        if vtx.persistence != Persistence::Empty
+            && self.vertices.get(left).unwrap().persistence == Persistence::Empty
+        {
+            self.put(left, &vtx.data);
+        }
+        self.erase(right);
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+use std::cell::RefCell;
+
+#[test]
+fn merges_empty_leaves_into_parents() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(1, 4, Label::Alpha(1));
+    g.bind(2, 5, Label::Alpha(0));
+    g.put(5, &Hex::from(42));
+    let seen = RefCell::new(vec![]);
+    let total = g.reduce(|g, left, right| {
+        seen.borrow_mut().push((left, right));
+        g.kids(left).any(|(_, to)| *to == right)
+            && g.kids(right).count() == 0
+            && g.vertices.get(right).unwrap().persistence == Persistence::Empty
+    });
+    assert_eq!(2, total);
+    assert_eq!(4, g.len());
+    assert_eq!(0, g.kids(1).count());
+    let calls = seen.borrow();
+    let merged = calls.iter().position(|p| *p == (1, 3)).unwrap();
+    assert!(!calls[merged + 1..].iter().any(|(l, r)| *l == 3 || *r == 3));
+    assert_eq!(5, g.kid(2, Label::Alpha(0)).unwrap());
+}
+
+#[test]
+fn moves_kids_and_data() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(1));
+    g.bind(3, 1, Label::Alpha(0));
+    g.put(1, &Hex::from(7));
+    assert_eq!(1, g.reduce(|_, left, right| left == 0 && right == 1));
+    assert_eq!(2, g.kid(0, Label::Alpha(1)).unwrap());
+    assert_eq!(0, g.kid(3, Label::Alpha(0)).unwrap());
+    assert!(g.kid(0, Label::Alpha(0)).is_none());
+    assert_eq!(7, g.data(0).unwrap().to_i64().unwrap());
+}
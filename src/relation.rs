@@ -0,0 +1,106 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::HashSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Get all pairs of vertices connected by at least one edge,
+    /// ignoring labels, which is the binary relation of the graph.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 1, Label::Alpha(1));
+    /// assert_eq!(1, g.to_binary_relation().len());
+    /// ```
+    #[must_use]
+    pub fn to_binary_relation(&self) -> HashSet<(usize, usize)> {
+        self.keys()
+            .into_iter()
+            .flat_map(|v| self.kids(v).map(move |(_, to)| (v, *to)))
+            .collect()
+    }
+
+    /// Check whether each vertex has an edge to itself.
+    #[must_use]
+    pub fn is_reflexive(&self) -> bool {
+        let rel = self.to_binary_relation();
+        self.keys().into_iter().all(|v| rel.contains(&(v, v)))
+    }
+
+    /// Check whether for each edge from `a` to `b` there is
+    /// an edge from `b` to `a`.
+    #[must_use]
+    pub fn is_symmetric(&self) -> bool {
+        let rel = self.to_binary_relation();
+        rel.iter().all(|(a, b)| rel.contains(&(*b, *a)))
+    }
+
+    /// Check whether for each pair of edges from `a` to `b` and
+    /// from `b` to `c` there is an edge from `a` to `c`.
+    #[must_use]
+    pub fn is_transitive(&self) -> bool {
+        let rel = self.to_binary_relation();
+        rel.iter()
+            .all(|(a, b)| self.kids(*b).all(|(_, c)| rel.contains(&(*a, *c))))
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn checks_reflexivity() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+        g.bind(v, v, Label::Alpha(0));
+    }
+    g.bind(0, 1, Label::Alpha(1));
+    assert!(g.is_reflexive());
+    assert!(!g.is_symmetric());
+    g.add(3);
+    assert!(!g.is_reflexive());
+}
+
+#[test]
+fn checks_transitivity_and_symmetry() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    assert!(!g.is_transitive());
+    g.bind(0, 2, Label::Alpha(1));
+    assert!(g.is_transitive());
+    assert!(!g.is_symmetric());
+    g.bind(1, 0, Label::Alpha(1));
+    g.bind(2, 1, Label::Alpha(1));
+    g.bind(2, 0, Label::Alpha(2));
+    assert!(g.is_symmetric());
+    assert_eq!(6, g.to_binary_relation().len());
+}
@@ -0,0 +1,76 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+
+impl<const N: usize> Sodg<N> {
+    /// Find all vertices that have no incoming edges, sorted by their IDs.
+    ///
+    /// In a tree there is exactly one such vertex, in a DAG there may be
+    /// a few of them, while in a strongly connected graph there are none.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// assert_eq!(vec![0], g.root_candidates());
+    /// ```
+    #[must_use]
+    pub fn root_candidates(&self) -> Vec<usize> {
+        let degrees = self.in_degrees();
+        let mut roots: Vec<usize> = self
+            .keys()
+            .into_iter()
+            .filter(|v| degrees[v] == 0)
+            .collect();
+        roots.sort_unstable();
+        roots
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn finds_root_of_a_tree() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(1);
+    g.add(2);
+    g.add(3);
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(1, 3, Label::Alpha(1));
+    assert_eq!(vec![1], g.root_candidates());
+    g.bind(3, 1, Label::Alpha(0));
+    assert!(g.root_candidates().is_empty());
+}
+
+#[test]
+fn finds_one_root_per_component() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    assert_eq!(vec![0, 2], g.root_candidates());
+}
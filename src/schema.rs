@@ -0,0 +1,148 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, SchemaViolation, Sodg};
+use anyhow::{anyhow, Result};
+use std::collections::HashSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Check this graph against a schema, which is another graph.
+    ///
+    /// Every vertex of the schema is an expected type of vertex, while its
+    /// edges are the edges that such a vertex must have. If a schema vertex
+    /// has data, the matching vertex must have non-empty data too. The
+    /// walk starts at `root` and `schema_root` and goes along the edges
+    /// of the schema. An empty list of violations means that the graph
+    /// is valid:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, SchemaViolation, Sodg};
+    /// let mut schema : Sodg<16> = Sodg::empty(256);
+    /// schema.add(0);
+    /// schema.add(1);
+    /// schema.bind(0, 1, Label::Alpha(0));
+    /// schema.put(1, &Hex::from(1));
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(5);
+    /// assert_eq!(
+    ///     vec![SchemaViolation::MissingEdge { from: 5, label: "α0".to_string() }],
+    ///     g.verify_schema(&schema, 5, 0).unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any of the roots is absent, an error will be returned.
+    pub fn verify_schema(
+        &self,
+        schema: &Self,
+        root: usize,
+        schema_root: usize,
+    ) -> Result<Vec<SchemaViolation>> {
+        if !self.alive(root) {
+            return Err(anyhow!("Can't find ν{root} in the graph"));
+        }
+        if !schema.alive(schema_root) {
+            return Err(anyhow!("Can't find ν{schema_root} in the schema"));
+        }
+        let mut violations = vec![];
+        let mut seen = HashSet::new();
+        let mut todo = vec![(root, schema_root)];
+        while let Some((v, s)) = todo.pop() {
+            if !seen.insert((v, s)) {
+                continue;
+            }
+            if schema.peek(s).is_some_and(|d| !d.is_empty())
+                && self.peek(v).is_none_or(Hex::is_empty)
+            {
+                violations.push(SchemaViolation::MissingData { vertex: v });
+            }
+            let mut edges: Vec<_> = schema.kids(s).collect();
+            edges.sort();
+            for (a, to) in edges {
+                match self.kid(v, *a) {
+                    Some(k) => todo.push((k, *to)),
+                    None => violations.push(SchemaViolation::MissingEdge {
+                        from: v,
+                        label: a.to_string(),
+                    }),
+                }
+            }
+        }
+        Ok(violations)
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+fn person() -> Sodg<16> {
+    let mut schema = Sodg::empty(256);
+    schema.add(0);
+    schema.add(1);
+    schema.add(2);
+    schema.bind(0, 1, Label::Alpha(0));
+    schema.bind(0, 2, Label::Alpha(1));
+    schema.put(1, &Hex::from(1));
+    schema
+}
+
+#[test]
+fn accepts_valid_graph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(7);
+    g.add(8);
+    g.add(9);
+    g.bind(7, 8, Label::Alpha(0));
+    g.bind(7, 9, Label::Alpha(1));
+    g.put(8, &Hex::from_str_bytes("Jeff"));
+    assert!(g.verify_schema(&person(), 7, 0).unwrap().is_empty());
+}
+
+#[test]
+fn reports_missing_edge() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(7);
+    g.add(8);
+    g.bind(7, 8, Label::Alpha(0));
+    g.put(8, &Hex::from_str_bytes("Jeff"));
+    assert_eq!(
+        vec![SchemaViolation::MissingEdge {
+            from: 7,
+            label: Label::Alpha(1).to_string()
+        }],
+        g.verify_schema(&person(), 7, 0).unwrap()
+    );
+}
+
+#[test]
+fn reports_missing_data() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(7);
+    g.add(8);
+    g.add(9);
+    g.bind(7, 8, Label::Alpha(0));
+    g.bind(7, 9, Label::Alpha(1));
+    assert_eq!(
+        vec![SchemaViolation::MissingData { vertex: 8 }],
+        g.verify_schema(&person(), 7, 0).unwrap()
+    );
+}
@@ -21,23 +21,61 @@
 use crate::{Hex, Script};
 use crate::{Label, Sodg};
 use anyhow::{anyhow, Context, Result};
-use lazy_static::lazy_static;
 use log::trace;
 use regex::Regex;
 use std::collections::HashMap;
 use std::str::FromStr;
+use std::sync::LazyLock;
 
 impl Script {
     /// Make a new one, parsing a string with instructions.
     ///
     /// Instructions
-    /// must be separated by semicolon. There are just three of them
-    /// possible: `ADD`, `BIND`, and `PUT`. The arguments must be
+    /// must be separated by semicolon. These are possible: `ADD`, `BIND`,
+    /// `BIND_BACK`, `DELETE`, `UNBIND`, `PUT`, `PUTSTR`, `COPY_SUBGRAPH`, `RENAME_EDGE`, `ASSERT_REACHABLE`,
+    /// `ASSERT_NOT_REACHABLE`, `GENERATE`, `CHECKPOINT`, `ROLLBACK`,
+    /// and `CLEAR_CHECKPOINT`. The arguments must be
     /// separated by a comma. An argument may either be 1) a positive integer
     /// (possibly prepended by `ν`),
     /// 2) a variable started with `$`, 3) an attribute name, or
     /// 4) data in `XX-XX-...` hexadecimal format.
     ///
+    /// The `PUTSTR` command takes everything after its first comma
+    /// as a UTF-8 string, without quotes, for example `PUTSTR($ν1, hello, world)`.
+    /// There is no quoting, so the string can't contain `)`, `;`, or `#`,
+    /// and leading and trailing spaces are trimmed. Use `PUT` with
+    /// hexadecimal data for anything else.
+    ///
+    /// The `DELETE(v)` command removes the vertex `v` together with all
+    /// edges pointing to it, while `UNBIND(v, label)` removes the
+    /// edge `v.label`.
+    ///
+    /// The `BIND_BACK(child, parent, label)` command makes an edge from
+    /// `child` back to `parent`, which must already have an edge to `child`.
+    ///
+    /// The `COPY_SUBGRAPH(src, dst)` command replicates the vertex `src`,
+    /// together with everything reachable from it, into the vertex `dst`
+    /// (which is added, if absent). New vertices get fresh IDs.
+    ///
+    /// The `RENAME_EDGE(v, old, new)` command changes the label of an edge
+    /// of the vertex `v`, see [`Sodg::rename_edge`].
+    ///
+    /// The `ASSERT_REACHABLE(v1, v2)` command fails the script if there is
+    /// no path from `v1` to `v2`, see [`Sodg::is_reachable`], while
+    /// `ASSERT_NOT_REACHABLE(v1, v2)` fails it if there is one.
+    ///
+    /// The `GENERATE(n, "pattern")` command deploys the pattern `n` times,
+    /// replacing `{i}` with the zero-based number of the iteration, for
+    /// example `GENERATE(3, "ADD($ν); BIND(0, $ν, item_{i})")`. Variables
+    /// first used inside the pattern get fresh vertices at each iteration.
+    ///
+    /// The `LOAD(v, "file")` command puts the content of the file into
+    /// the vertex `v`, but only if the `script-io` feature is enabled.
+    ///
+    /// The `CHECKPOINT(name)` command remembers the current state of the
+    /// graph, `ROLLBACK(name)` brings the graph back to it, see
+    /// [`Script::rollback`], and `CLEAR_CHECKPOINT(name)` forgets it.
+    ///
     /// For example:
     ///
     /// ```
@@ -57,6 +95,7 @@ impl Script {
         Self {
             txt: s.to_string(),
             vars: HashMap::new(),
+            checkpoints: HashMap::new(),
         }
     }
 
@@ -76,29 +115,116 @@ impl Script {
         Ok(pos)
     }
 
+    /// Deploy each script to a fresh [`Sodg`] and merge the result into
+    /// the `target`, mapping the vertex zero to the vertex zero.
+    ///
+    /// Scripts don't share variables, since each of them is deployed
+    /// to its own graph. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Script, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// Script::deploy_and_merge(
+    ///     &mut g,
+    ///     &["ADD(0); ADD(1); BIND(0, 1, foo);", "ADD(0); ADD(1); BIND(0, 1, bar);"],
+    /// ).unwrap();
+    /// assert_eq!(3, g.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If any script can't be deployed or merged, an error will be returned.
+    pub fn deploy_and_merge<const N: usize>(target: &mut Sodg<N>, scripts: &[&str]) -> Result<()> {
+        for (pos, txt) in scripts.iter().enumerate() {
+            let mut g = Sodg::empty(target.vertices.capacity());
+            Self::from_str(txt)
+                .deploy_to(&mut g)
+                .with_context(|| format!("Failure in the script no.{pos}"))?;
+            target
+                .merge_into_root(&g)
+                .with_context(|| format!("Can't merge the script no.{pos}"))?;
+        }
+        Ok(())
+    }
+
+    /// Bring the graph back to the state it had at the moment
+    /// of the `CHECKPOINT` command with this name.
+    ///
+    /// The checkpoint stays, so it's possible to roll back to it again.
+    /// Callbacks registered by [`Sodg::watch_vertex`] are kept.
+    /// It's possible to call it after a failed [`Script::deploy_to`].
+    ///
+    /// # Errors
+    ///
+    /// If there is no such checkpoint or it was made for a graph of
+    /// a different type, an error will be returned.
+    pub fn rollback<const N: usize>(&self, name: &str, g: &mut Sodg<N>) -> Result<()> {
+        let saved = self
+            .checkpoints
+            .get(name)
+            .with_context(|| format!("There is no checkpoint '{name}'"))?
+            .downcast_ref::<Sodg<N>>()
+            .with_context(|| format!("The checkpoint '{name}' is of a different graph"))?;
+        let watchers = std::mem::take(&mut g.watchers);
+        *g = saved.clone();
+        g.watchers = watchers;
+        Ok(())
+    }
+
     /// Get all commands.
     fn commands(&self) -> Vec<String> {
-        lazy_static! {
-            static ref STRIP_COMMENTS: Regex = Regex::new("#.*\n").unwrap();
-        }
+        static STRIP_COMMENTS: LazyLock<Regex> = LazyLock::new(|| Regex::new("#.*\n").unwrap());
         let text = self.txt.as_str();
         let clean: &str = &STRIP_COMMENTS.replace_all(text, "");
+        let mut quoted = false;
         clean
-            .split(';')
+            .split(|c| {
+                if c == '"' {
+                    quoted = !quoted;
+                }
+                c == ';' && !quoted
+            })
             .map(str::trim)
             .filter(|t| !t.is_empty())
             .map(ToString::to_string)
             .collect()
     }
 
+    /// Deploy the `pattern` to the [`Sodg`] `n` times, replacing
+    /// `{i}` with the number of the iteration.
+    ///
+    /// Variables that are not yet known when the generation starts
+    /// are allocated again at each iteration.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to deploy, an error will be returned.
+    #[allow(clippy::literal_string_with_formatting_args)]
+    fn generate<const N: usize>(&self, n: usize, pattern: &str, g: &mut Sodg<N>) -> Result<()> {
+        for i in 0..n {
+            let mut s = Self::from_str(pattern.replace("{i}", &i.to_string()).as_str());
+            s.vars.clone_from(&self.vars);
+            s.deploy_to(g)
+                .with_context(|| format!("Failure at the iteration no.{i}"))?;
+        }
+        Ok(())
+    }
+
     /// Deploy a single command to the [`Sodg`].
     ///
     /// # Errors
     ///
     /// If impossible to deploy, an error will be returned.
     fn deploy_one<const N: usize>(&mut self, cmd: &str, g: &mut Sodg<N>) -> Result<()> {
-        lazy_static! {
-            static ref LINE: Regex = Regex::new("^([A-Z]+) *\\(([^)]*)\\)$").unwrap();
+        static GENERATE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^GENERATE *\\( *([0-9]+) *, *\"(.*)\" *\\)$").unwrap());
+        static LINE: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^([A-Z_]+) *\\(([^)]*)\\)$").unwrap());
+        if let Some(cap) = GENERATE.captures(cmd) {
+            let n = usize::from_str(&cap[1])?;
+            return self.generate(n, &cap[2], g);
         }
         let cap = LINE
             .captures(cmd)
@@ -121,11 +247,67 @@ impl Script {
                     Label::from_str(args.get(2).with_context(|| "Label is expected")?.as_str())?;
                 g.bind(v1, v2, a);
             }
+            "BIND_BACK" => {
+                let child = self.parse(args.first().with_context(|| "Child is expected")?, g)?;
+                let parent = self.parse(args.get(1).with_context(|| "Parent is expected")?, g)?;
+                let a =
+                    Label::from_str(args.get(2).with_context(|| "Label is expected")?.as_str())?;
+                if !g.kids(parent).any(|(_, to)| *to == child) {
+                    return Err(anyhow!("Vertex ν{child} is not a kid of ν{parent}"));
+                }
+                g.bind(child, parent, a);
+            }
+            "DELETE" | "UNBIND" => self.destroy(&cap[1], &args, g)?,
             "PUT" => {
                 let v = self.parse(args.first().with_context(|| "V is expected")?, g)?;
                 let d = Self::parse_data(args.get(1).with_context(|| "Data is expected")?)?;
                 g.put(v, &d);
             }
+            "PUTSTR" => {
+                let (head, tail) = cap[2]
+                    .split_once(',')
+                    .with_context(|| "V and string are expected")?;
+                let v = self.parse(head.trim(), g)?;
+                g.put(v, &Hex::from_str_bytes(tail.trim()));
+            }
+            #[cfg(feature = "script-io")]
+            "LOAD" => self.load(&cap[2], g)?,
+            "COPY_SUBGRAPH" => {
+                let src = self.parse(args.first().with_context(|| "Source is expected")?, g)?;
+                let dst = self.parse(args.get(1).with_context(|| "Target is expected")?, g)?;
+                g.add_if_absent(dst);
+                let sub = g.slice(src)?;
+                g.merge(&sub, dst, src)?;
+            }
+            "CHECKPOINT" | "ROLLBACK" | "CLEAR_CHECKPOINT" => {
+                let name = args.first().with_context(|| "Name is expected")?;
+                self.checkpoint(&cap[1], name, g)?;
+            }
+            "RENAME_EDGE" => {
+                let v = self.parse(args.first().with_context(|| "V is expected")?, g)?;
+                let old = Label::from_str(
+                    args.get(1)
+                        .with_context(|| "Old label is expected")?
+                        .as_str(),
+                )?;
+                let new = Label::from_str(
+                    args.get(2)
+                        .with_context(|| "New label is expected")?
+                        .as_str(),
+                )?;
+                g.rename_edge(v, old, new)?;
+            }
+            "ASSERT_REACHABLE" | "ASSERT_NOT_REACHABLE" => {
+                let v1 = self.parse(args.first().with_context(|| "V1 is expected")?, g)?;
+                let v2 = self.parse(args.get(1).with_context(|| "V2 is expected")?, g)?;
+                let expected = &cap[1] == "ASSERT_REACHABLE";
+                if g.is_reachable(v1, v2) != expected {
+                    return Err(anyhow!(
+                        "Vertex ν{v2} is {}reachable from ν{v1}",
+                        if expected { "not " } else { "" }
+                    ));
+                }
+            }
             cmd => {
                 return Err(anyhow!("Unknown command: {cmd}"));
             }
@@ -133,16 +315,75 @@ impl Script {
         Ok(())
     }
 
+    /// Deploy one of the commands that remove vertices or edges.
+    ///
+    /// # Errors
+    ///
+    /// If the vertex or the edge doesn't exist, an error will be returned.
+    fn destroy<const N: usize>(
+        &mut self,
+        cmd: &str,
+        args: &[String],
+        g: &mut Sodg<N>,
+    ) -> Result<()> {
+        let v = self.parse(args.first().with_context(|| "V is expected")?, g)?;
+        if cmd == "DELETE" {
+            return g.remove(v);
+        }
+        let a = Label::from_str(args.get(1).with_context(|| "Label is expected")?)?;
+        g.unbind(v, a)
+            .with_context(|| format!("There is no edge ν{v}.{a}"))?;
+        Ok(())
+    }
+
+    /// Deploy one of the commands that deal with checkpoints.
+    ///
+    /// # Errors
+    ///
+    /// If there is no such checkpoint, an error will be returned.
+    fn checkpoint<const N: usize>(&mut self, cmd: &str, name: &str, g: &mut Sodg<N>) -> Result<()> {
+        match cmd {
+            "CHECKPOINT" => {
+                self.checkpoints
+                    .insert(name.to_string(), Box::new(g.clone()));
+            }
+            "ROLLBACK" => self.rollback(name, g)?,
+            _ => {
+                self.checkpoints
+                    .remove(name)
+                    .with_context(|| format!("There is no checkpoint '{name}'"))?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Read the file and put its content into the vertex, where
+    /// the `args` are the vertex and the path, possibly quoted.
+    ///
+    /// # Errors
+    ///
+    /// If the file can't be read, an error will be returned.
+    #[cfg(feature = "script-io")]
+    fn load<const N: usize>(&mut self, args: &str, g: &mut Sodg<N>) -> Result<()> {
+        let (head, tail) = args
+            .split_once(',')
+            .with_context(|| "V and file name are expected")?;
+        let v = self.parse(head.trim(), g)?;
+        let path = tail.trim().trim_matches('"');
+        let bytes = std::fs::read(path).with_context(|| format!("Can't read '{path}'"))?;
+        g.put(v, &Hex::from_vec(bytes));
+        Ok(())
+    }
+
     /// Parse data.
     ///
     /// # Errors
     ///
     /// If impossible to parse, an error will be returned.
     fn parse_data(s: &str) -> Result<Hex> {
-        lazy_static! {
-            static ref DATA_STRIP: Regex = Regex::new("[ \t\n\r\\-]").unwrap();
-            static ref DATA: Regex = Regex::new("^[0-9A-Fa-f]{2}([0-9A-Fa-f]{2})*$").unwrap();
-        }
+        static DATA_STRIP: LazyLock<Regex> = LazyLock::new(|| Regex::new("[ \t\n\r\\-]").unwrap());
+        static DATA: LazyLock<Regex> =
+            LazyLock::new(|| Regex::new("^[0-9A-Fa-f]{2}([0-9A-Fa-f]{2})*$").unwrap());
         let d: &str = &DATA_STRIP.replace_all(s, "");
         if DATA.is_match(d) {
             let bytes: Vec<u8> = (0..d.len())
@@ -180,6 +421,87 @@ impl Script {
     }
 }
 
+impl<const N: usize> Sodg<N> {
+    /// Deploy a script to the graph, which is a shorthand for
+    /// [`Script::from_str`] followed by [`Script::deploy_to`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.apply_script("ADD(0); ADD($ν1); BIND(0, $ν1, foo);").unwrap();
+    /// assert_eq!(1, g.kid(0, Label::from_str("foo").unwrap()).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If impossible to deploy, an error will be returned.
+    pub fn apply_script(&mut self, script: &str) -> Result<usize> {
+        Script::from_str(script).deploy_to(self)
+    }
+
+    /// Deploy a script to the graph, where the `$root` variable
+    /// is the vertex `root`, which is added, if absent.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.apply_script_with_root("ADD($ν1); BIND($root, $ν1, foo);", 42).unwrap();
+    /// assert!(g.kid(42, Label::from_str("foo").unwrap()).is_some());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If impossible to deploy, an error will be returned.
+    pub fn apply_script_with_root(&mut self, script: &str, root: usize) -> Result<usize> {
+        self.add_if_absent(root);
+        let mut s = Script::from_str(script);
+        s.vars.insert("root".to_string(), root);
+        s.deploy_to(self)
+    }
+
+    /// Print the graph as a script, which, being deployed to an
+    /// empty [`Sodg`], reproduces it. All `ADD` commands go first,
+    /// then all `BIND` commands, and then all `PUT` commands, each
+    /// group sorted by vertices and labels, one command per line.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// assert_eq!("ADD(0);\nADD(1);\nBIND(0, 1, α0);\n", g.to_script());
+    /// ```
+    #[must_use]
+    pub fn to_script(&self) -> String {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut lines: Vec<String> = keys.iter().map(|v| format!("ADD({v});")).collect();
+        for v in &keys {
+            let mut kids: Vec<(Label, usize)> = self.kids(*v).map(|(a, to)| (*a, *to)).collect();
+            kids.sort_unstable();
+            for (a, to) in kids {
+                lines.push(format!("BIND({v}, {to}, {a});"));
+            }
+        }
+        for v in &keys {
+            if let Some(d) = self.peek(*v).filter(|d| !d.is_empty()) {
+                lines.push(format!("PUT({v}, {d});"));
+            }
+        }
+        lines.push(String::new());
+        lines.join("\n")
+    }
+}
+
 #[cfg(test)]
 use std::str;
 
@@ -198,3 +520,235 @@ fn simple_command() {
     assert_eq!("привет", g.data(1).unwrap().to_utf8().unwrap());
     assert_eq!(1, g.kid(0, Label::from_str("foo").unwrap()).unwrap());
 }
+
+#[test]
+fn puts_strings() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    let mut s = Script::from_str("ADD($ν1); PUTSTR($ν1, hello); ADD($ν2); PUTSTR($ν2, привет);");
+    s.deploy_to(&mut g).unwrap();
+    assert_eq!(b"hello".to_vec(), g.data(0).unwrap().to_vec());
+    assert_eq!("привет".as_bytes().to_vec(), g.data(1).unwrap().to_vec());
+}
+
+#[test]
+fn puts_string_with_commas() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    let mut s = Script::from_str("ADD(0); PUTSTR(ν0,  hello, world );");
+    s.deploy_to(&mut g).unwrap();
+    assert_eq!(
+        "hello, world",
+        str::from_utf8(g.data(0).unwrap().bytes()).unwrap()
+    );
+}
+
+#[test]
+fn rejects_string_with_parenthesis() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    let mut s = Script::from_str("ADD(0); PUTSTR(0, (hello));");
+    assert!(s.deploy_to(&mut g).is_err());
+}
+
+#[test]
+fn copies_subgraph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    let mut s = Script::from_str(
+        "ADD(0); ADD(1); BIND(0, 1, a); PUT(1, CA-FE);
+        COPY_SUBGRAPH(0, $ν2);",
+    );
+    s.deploy_to(&mut g).unwrap();
+    assert_eq!(4, g.len());
+    assert_eq!(1, g.kid(0, Label::from_str("a").unwrap()).unwrap());
+    let copy = g.kid(2, Label::from_str("a").unwrap()).unwrap();
+    assert_eq!(3, copy);
+    assert_eq!("CA-FE", g.data(copy).unwrap().print());
+}
+
+#[test]
+fn applies_script_like_deploy() {
+    let txt = "ADD(0); ADD($ν1); BIND(0, $ν1, foo); PUTSTR($ν1, hello);";
+    let mut first: Sodg<16> = Sodg::empty(256);
+    Script::from_str(txt).deploy_to(&mut first).unwrap();
+    let mut second: Sodg<16> = Sodg::empty(256);
+    assert_eq!(4, second.apply_script(txt).unwrap());
+    first.assert_structurally_equal(&second);
+}
+
+#[test]
+fn applies_script_with_root() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script_with_root("ADD($ν1); BIND($root, $ν1, foo);", 7)
+        .unwrap();
+    assert_eq!(2, g.len());
+    let kid = g.kid(7, Label::from_str("foo").unwrap()).unwrap();
+    assert_ne!(7, kid);
+}
+
+#[test]
+fn renames_edges() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script("ADD(0); ADD(1); BIND(0, 1, old); RENAME_EDGE(0, old, new);")
+        .unwrap();
+    assert_eq!(Some(1), g.kid(0, Label::from_str("new").unwrap()));
+    assert_eq!(None, g.kid(0, Label::from_str("old").unwrap()));
+    let err = g.apply_script("RENAME_EDGE(0, old, new);").unwrap_err();
+    assert!(format!("{err:#}").contains("RENAME_EDGE(0, old, new)"));
+    assert!(format!("{err:#}").contains("There is no edge ν0.old"));
+}
+
+#[test]
+fn asserts_reachability() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script("ADD(0); ADD($ν1); ASSERT_NOT_REACHABLE(0, $ν1); BIND(0, $ν1, foo); ASSERT_REACHABLE(0, $ν1);")
+        .unwrap();
+    let err = g.apply_script("ASSERT_REACHABLE(1, 0);").unwrap_err();
+    assert!(format!("{err:#}").contains("Vertex ν0 is not reachable from ν1"));
+    let err = g.apply_script("ASSERT_NOT_REACHABLE(0, 1);").unwrap_err();
+    assert!(format!("{err:#}").contains("Vertex ν1 is reachable from ν0"));
+}
+
+#[test]
+#[allow(clippy::literal_string_with_formatting_args)]
+fn generates_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script("ADD(0); GENERATE(5, \"ADD($ν); BIND(0, $ν, item_{i})\");")
+        .unwrap();
+    assert_eq!(6, g.len());
+    for i in 0..5 {
+        let kid = g
+            .kid(0, Label::from_str(format!("item_{i}").as_str()).unwrap())
+            .unwrap();
+        assert_eq!(i + 1, kid);
+    }
+    assert_eq!(
+        2,
+        g.apply_script("GENERATE(0, \"ADD(42)\"); ADD(1);").unwrap()
+    );
+    assert_eq!(6, g.len());
+}
+
+#[test]
+fn binds_back() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script(
+        "ADD(0); ADD(1); ADD(2); BIND(0, 1, kid); BIND(1, 2, kid);
+        BIND_BACK(1, 0, parent); BIND_BACK(2, 1, parent);",
+    )
+    .unwrap();
+    let parent = Label::from_str("parent").unwrap();
+    assert_eq!(Some(0), g.kid(1, parent));
+    assert_eq!(Some(1), g.kid(2, parent));
+    assert_eq!(None, g.kid(0, parent));
+    let err = g.apply_script("BIND_BACK(0, 2, parent);").unwrap_err();
+    assert!(format!("{err:#}").contains("Vertex ν0 is not a kid of ν2"));
+}
+
+#[test]
+fn rolls_back_after_failure() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    let mut s = Script::from_str(
+        "ADD(0); ADD(1); BIND(0, 1, a); CHECKPOINT(before);
+        ADD(2); BIND(1, 2, b); PUTSTR(0, x); ASSERT_REACHABLE(2, 0);",
+    );
+    assert!(s.deploy_to(&mut g).is_err());
+    assert_eq!(3, g.len());
+    s.rollback("before", &mut g).unwrap();
+    assert_eq!(2, g.len());
+    assert_eq!(None, g.kid(1, Label::from_str("b").unwrap()));
+    assert!(g.data(0).is_none());
+    assert!(s.rollback("unknown", &mut g).is_err());
+}
+
+#[test]
+fn keeps_many_checkpoints() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script(
+        "ADD(0); CHECKPOINT(one); ADD(1); CHECKPOINT(two); ADD(2);
+        ROLLBACK(two); ASSERT_REACHABLE(1, 1); ROLLBACK(one); ADD(5);
+        CLEAR_CHECKPOINT(two);",
+    )
+    .unwrap();
+    assert_eq!(2, g.len());
+    assert!(g.apply_script("ROLLBACK(one);").is_err());
+    assert!(g
+        .apply_script("CHECKPOINT(x); CLEAR_CHECKPOINT(x); ROLLBACK(x);")
+        .is_err());
+}
+
+#[test]
+fn deploys_and_merges() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    Script::deploy_and_merge(
+        &mut g,
+        &[
+            "ADD(0); ADD($ν1); BIND(0, $ν1, foo); PUTSTR($ν1, hello);",
+            "ADD(0); ADD($ν1); BIND(0, $ν1, bar);",
+        ],
+    )
+    .unwrap();
+    assert_eq!(3, g.len());
+    let foo = g.kid(0, Label::from_str("foo").unwrap()).unwrap();
+    assert_eq!("hello", g.data(foo).unwrap().to_utf8().unwrap());
+    let err = Script::deploy_and_merge(&mut g, &["ADD(0);", "BIND(0, 1, x);"]).unwrap_err();
+    assert!(format!("{err:#}").contains("script no.1"));
+}
+
+#[cfg(feature = "script-io")]
+#[test]
+fn loads_files() {
+    let tmp = tempfile::TempDir::new().unwrap();
+    let small = tmp.path().join("config.json");
+    std::fs::write(&small, "{\"a\": 1}").unwrap();
+    let empty = tmp.path().join("empty.bin");
+    std::fs::write(&empty, "").unwrap();
+    let large = tmp.path().join("large.bin");
+    std::fs::write(&large, vec![7; 2 * 1024 * 1024]).unwrap();
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script(&format!(
+        "ADD(0); LOAD(0, \"{}\"); ADD(1); LOAD(1, {}); ADD(2); LOAD(2, {});",
+        small.display(),
+        empty.display(),
+        large.display()
+    ))
+    .unwrap();
+    assert_eq!(b"{\"a\": 1}".to_vec(), g.data(0).unwrap().to_vec());
+    assert!(g.data(1).unwrap().is_empty());
+    assert_eq!(2 * 1024 * 1024, g.data(2).unwrap().len());
+    let err = g.apply_script("LOAD(0, /no/such/file);").unwrap_err();
+    assert!(format!("{err:#}").contains("Can't read '/no/such/file'"));
+}
+
+#[test]
+fn deletes_and_unbinds() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script(
+        "ADD(0); ADD(1); ADD(2); BIND(0, 1, a); BIND(0, 2, b); BIND(2, 1, c);
+        DELETE(1); UNBIND(0, b);",
+    )
+    .unwrap();
+    assert_eq!(2, g.len());
+    assert_eq!(0, g.kids(0).count());
+    assert_eq!(0, g.kids(2).count());
+    let err = g.apply_script("DELETE(1);").unwrap_err();
+    assert!(format!("{err:#}").contains("Can't find ν1"));
+    let err = g.apply_script("UNBIND(0, b);").unwrap_err();
+    assert!(format!("{err:#}").contains("There is no edge ν0.b"));
+}
+
+#[test]
+fn prints_itself_as_script() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.apply_script(
+        "ADD(0); ADD(3); ADD(1); BIND(0, 3, foo); BIND(0, 1, α0); BIND(3, 1, ρ);
+        PUT(1, CA-FE); PUT(3, 00);",
+    )
+    .unwrap();
+    let script = g.to_script();
+    let mut copy: Sodg<16> = Sodg::empty(256);
+    copy.apply_script(&script).unwrap();
+    assert_eq!(script, copy.to_script());
+    assert_eq!(g.keys().len(), copy.keys().len());
+    assert_eq!(Some(3), copy.kid(0, Label::from_str("foo").unwrap()));
+    assert_eq!(Some(1), copy.kid(3, Label::from_str("ρ").unwrap()));
+    assert_eq!(vec![0xCA, 0xFE], copy.peek(1).unwrap().to_vec());
+}
@@ -18,14 +18,21 @@
 // OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
 // SOFTWARE.
 
-use crate::Sodg;
-use anyhow::{Context, Result};
-use bincode::{deserialize, serialize};
+use crate::{Hex, Sodg};
+use anyhow::{anyhow, Context, Result};
+use bincode::{deserialize, deserialize_from, serialize, serialize_into};
 use log::trace;
 use std::fs;
+use std::io::{Read, Write};
 use std::path::Path;
 use std::time::Instant;
 
+/// The first bytes of every file made by [`Sodg::save`].
+const MAGIC: &[u8; 4] = b"SODG";
+
+/// The version of the binary format, which goes right after the [`MAGIC`].
+const VERSION: u16 = 1;
+
 impl<const N: usize> Sodg<N> {
     /// Save the entire [`Sodg`] into a binary file.
     ///
@@ -33,12 +40,18 @@ impl<const N: usize> Sodg<N> {
     /// The function returns the size of the file just saved. In order
     /// to restore from the file, use [`Sodg::load`].
     ///
+    /// The file starts with the `SODG` magic number and a two-byte
+    /// big-endian version of the format, followed by the graph
+    /// encoded with `bincode`.
+    ///
     /// # Errors
     ///
     /// If impossible to save, an error will be returned.
     pub fn save(&self, path: &Path) -> Result<usize> {
         let start = Instant::now();
-        let bytes: Vec<u8> = serialize(self).with_context(|| "Failed to serialize")?;
+        let mut bytes: Vec<u8> = MAGIC.to_vec();
+        bytes.extend_from_slice(&VERSION.to_be_bytes());
+        bytes.extend(serialize(self).with_context(|| "Failed to serialize")?);
         let size = bytes.len();
         fs::write(path, bytes).with_context(|| format!("Can't write to {}", path.display()))?;
         trace!(
@@ -56,13 +69,28 @@ impl<const N: usize> Sodg<N> {
     ///
     /// # Errors
     ///
-    /// If impossible to load, an error will be returned.
+    /// If impossible to load, an error will be returned. It also happens
+    /// if the file doesn't start with the magic number or
+    /// its format version is not supported.
     pub fn load(path: &Path) -> Result<Self> {
         let start = Instant::now();
         let bytes =
             fs::read(path).with_context(|| format!("Can't read from {}", path.display()))?;
         let size = bytes.len();
-        let sodg: Self = deserialize(&bytes)
+        if size < MAGIC.len() + 2 || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(anyhow!(
+                "The file {} is not a SODG snapshot",
+                path.display()
+            ));
+        }
+        let version = u16::from_be_bytes([bytes[MAGIC.len()], bytes[MAGIC.len() + 1]]);
+        if version != VERSION {
+            return Err(anyhow!(
+                "The format version {version} of {} is not supported, {VERSION} is expected",
+                path.display()
+            ));
+        }
+        let sodg: Self = deserialize(&bytes[MAGIC.len() + 2..])
             .with_context(|| format!("Can't deserialize from {}", path.display()))?;
         trace!(
             "Deserialized {} vertices ({} bytes) from {} in {:?}",
@@ -73,14 +101,55 @@ impl<const N: usize> Sodg<N> {
         );
         Ok(sodg)
     }
+
+    /// Save all vertices with their data, but without edges, in a binary
+    /// format, to restore them later with [`Sodg::deserialize_vertices_into`].
+    ///
+    /// # Errors
+    ///
+    /// If impossible to write, an error will be returned.
+    pub fn serialize_vertices_only<W: Write>(&self, w: &mut W) -> Result<()> {
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let pairs: Vec<(usize, Option<Vec<u8>>)> = keys
+            .into_iter()
+            .map(|v| (v, self.peek(v).map(Hex::to_vec)))
+            .collect();
+        serialize_into(w, &pairs).with_context(|| format!("Can't write {} vertices", pairs.len()))
+    }
+
+    /// Read vertices saved by [`Sodg::serialize_vertices_only`] into
+    /// this graph.
+    ///
+    /// Absent vertices are added, while existing ones keep their edges
+    /// and only get new data, if there are data for them. The function
+    /// returns the number of vertices read.
+    ///
+    /// # Errors
+    ///
+    /// If impossible to read, or if some of the vertices don't fit into
+    /// the capacity of this graph, an error will be returned and the graph
+    /// will stay untouched.
+    pub fn deserialize_vertices_into<R: Read>(&mut self, r: &mut R) -> Result<usize> {
+        let pairs: Vec<(usize, Option<Vec<u8>>)> =
+            deserialize_from(r).context("Can't read vertices")?;
+        let cap = self.vertices.capacity();
+        if let Some((v, _)) = pairs.iter().find(|(v, _)| *v >= cap) {
+            return Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"));
+        }
+        for (v, data) in &pairs {
+            self.add_if_absent(*v);
+            if let Some(d) = data {
+                self.put(*v, &Hex::from_slice(d));
+            }
+        }
+        Ok(pairs.len())
+    }
 }
 
 #[cfg(test)]
 use tempfile::TempDir;
 
-#[cfg(test)]
-use crate::Hex;
-
 #[cfg(test)]
 use crate::Label;
 
@@ -110,3 +179,61 @@ fn saves_and_loads() {
     let after: Sodg<1> = Sodg::load(file.as_path()).unwrap();
     assert_eq!(g.inspect(0).unwrap(), after.inspect(0).unwrap());
 }
+
+#[test]
+fn rejects_foreign_files() {
+    let tmp = TempDir::new().unwrap();
+    let file = tmp.path().join("foo.sodg");
+    fs::write(&file, b"hello, world!").unwrap();
+    let err = Sodg::<16>::load(file.as_path()).unwrap_err();
+    assert!(err.to_string().contains("is not a SODG snapshot"));
+    let g: Sodg<16> = Sodg::empty(16);
+    g.save(file.as_path()).unwrap();
+    let mut bytes = fs::read(&file).unwrap();
+    assert_eq!(b"SODG", &bytes[..4]);
+    bytes[5] = 42;
+    fs::write(&file, bytes).unwrap();
+    let err = Sodg::<16>::load(file.as_path()).unwrap_err();
+    assert!(err.to_string().contains("version 42"));
+}
+
+#[test]
+fn saves_and_loads_vertices_only() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.put(1, &Hex::from_str_bytes("hello"));
+    let mut bytes = vec![];
+    g.serialize_vertices_only(&mut bytes).unwrap();
+    let mut empty: Sodg<16> = Sodg::empty(256);
+    assert_eq!(
+        2,
+        empty
+            .deserialize_vertices_into(&mut bytes.as_slice())
+            .unwrap()
+    );
+    assert_eq!(2, empty.len());
+    assert_eq!(0, empty.kids(0).count());
+    assert_eq!("hello", empty.data(1).unwrap().to_utf8().unwrap());
+    let mut copy = g.clone();
+    copy.put(1, &Hex::from_str_bytes("bye"));
+    copy.deserialize_vertices_into(&mut bytes.as_slice())
+        .unwrap();
+    assert_eq!(Some(1), copy.kid(0, Label::from_str("foo").unwrap()));
+    assert_eq!("hello", copy.data(1).unwrap().to_utf8().unwrap());
+}
+
+#[test]
+fn rejects_vertices_beyond_capacity() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(3);
+    g.add(100);
+    let mut bytes = vec![];
+    g.serialize_vertices_only(&mut bytes).unwrap();
+    let mut small: Sodg<16> = Sodg::empty(16);
+    assert!(small
+        .deserialize_vertices_into(&mut bytes.as_slice())
+        .is_err());
+    assert_eq!(0, small.len());
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use std::collections::HashSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Calculate the Jaccard index of the sets of kids of two vertices.
+    ///
+    /// The result is `1.0` if both vertices point to exactly the same
+    /// vertices (or both have no kids at all) and `0.0` if they have no
+    /// kids in common. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 2, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(7));
+    /// assert!(g.vertex_similarity_jaccard(0, 1) > 0.99);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If any of the vertices is absent, it will panic.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn vertex_similarity_jaccard(&self, a: usize, b: usize) -> f64 {
+        let left: HashSet<usize> = self.kids(a).map(|(_, to)| *to).collect();
+        let right: HashSet<usize> = self.kids(b).map(|(_, to)| *to).collect();
+        let union = left.union(&right).count();
+        if union == 0 {
+            return 1.0;
+        }
+        left.intersection(&right).count() as f64 / union as f64
+    }
+
+    /// Calculate the cosine similarity of two vertices, treating the
+    /// labels of their outgoing edges as binary vectors.
+    ///
+    /// The result is `0.0` if any of the vertices has no kids.
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 2, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(1, 0, Label::Alpha(1));
+    /// assert!((g.vertex_similarity_cosine(0, 1) - 0.5_f64.sqrt()).abs() < 1e-9);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// If any of the vertices is absent, it will panic.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn vertex_similarity_cosine(&self, a: usize, b: usize) -> f64 {
+        let left: HashSet<Label> = self.kids(a).map(|(l, _)| *l).collect();
+        let right: HashSet<Label> = self.kids(b).map(|(l, _)| *l).collect();
+        if left.is_empty() || right.is_empty() {
+            return 0.0;
+        }
+        let common = left.intersection(&right).count() as f64;
+        common / ((left.len() * right.len()) as f64).sqrt()
+    }
+}
+
+#[cfg(test)]
+fn fan() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..8 {
+        g.add(v);
+    }
+    g
+}
+
+#[test]
+fn finds_identical_neighborhoods() {
+    let mut g = fan();
+    for v in 2..5 {
+        g.bind(0, v, Label::Alpha(v));
+        g.bind(1, v, Label::Alpha(v + 10));
+    }
+    assert!((g.vertex_similarity_jaccard(0, 1) - 1.0).abs() < f64::EPSILON);
+    assert!(g.vertex_similarity_cosine(0, 1).abs() < f64::EPSILON);
+}
+
+#[test]
+fn finds_disjoint_neighborhoods() {
+    let mut g = fan();
+    g.bind(0, 2, Label::Alpha(0));
+    g.bind(1, 3, Label::Alpha(0));
+    assert!(g.vertex_similarity_jaccard(0, 1).abs() < f64::EPSILON);
+    assert!((g.vertex_similarity_cosine(0, 1) - 1.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn finds_partial_overlap() {
+    let mut g = fan();
+    for v in 2..5 {
+        g.bind(0, v, Label::Alpha(v));
+    }
+    g.bind(1, 4, Label::Alpha(4));
+    g.bind(1, 5, Label::Alpha(5));
+    g.bind(1, 6, Label::Alpha(6));
+    assert!((g.vertex_similarity_jaccard(0, 1) - 0.2).abs() < f64::EPSILON);
+}
@@ -19,14 +19,17 @@
 // SOFTWARE.
 
 use crate::{Label, Sodg};
-use anyhow::Result;
+use anyhow::{anyhow, Result};
 use log::trace;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 impl<const N: usize> Sodg<N> {
     /// Take a slice of the graph, keeping only the vertex specified
     /// by the locator and its kids, recursively found in the entire graph.
     ///
+    /// The data of the vertices is copied too, so the slice is a complete
+    /// copy of the subgraph.
+    ///
     /// # Errors
     ///
     /// If impossible to slice, an error will be returned.
@@ -44,7 +47,7 @@ impl<const N: usize> Sodg<N> {
     /// Take a slice of the graph, keeping only the vertex specified
     /// by the locator and its kids, recursively found in the entire graph,
     /// but only if the provided predicate agrees with the selection of
-    /// the kids.
+    /// the kids. The data of the vertices is copied too, as in [`Sodg::slice`].
     ///
     /// # Errors
     ///
@@ -76,27 +79,176 @@ impl<const N: usize> Sodg<N> {
                 }
             }
         }
-        let mut ng = Self::empty(self.vertices.capacity());
-        for (v1, vtx) in self.vertices.iter().filter(|(v, _)| done.contains(v)) {
-            if done.contains(&v1) {
-                ng.add(v1);
+        let ng = self.induced(&done);
+        trace!(
+            "#slice_some: taken {} vertices out of {} at ν{v}",
+            ng.len(),
+            self.len()
+        );
+        Ok(ng)
+    }
+
+    /// Take a slice of the graph, the same way as [`Sodg::slice_some`]
+    /// does, but following only the edges which labels are accepted
+    /// by the `filter`. For example, in order to cut the traversal at
+    /// the edges pointing to parents:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 0, Label::Greek('ρ'));
+    /// g.bind(1, 2, Label::Greek('ρ'));
+    /// let s = g.slice_by_label(1, |a| *a != Label::Greek('ρ')).unwrap();
+    /// assert_eq!(1, s.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If impossible to slice, an error will be returned.
+    pub fn slice_by_label(&self, v: usize, filter: impl Fn(&Label) -> bool) -> Result<Self> {
+        self.slice_some(v, |_, _, a| filter(&a))
+    }
+
+    /// Take a slice of the graph, keeping only the vertices that
+    /// are not farther than `hops` directed steps from the vertex `v`,
+    /// and the edges between them.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// assert_eq!(1, g.neighborhood_subgraph(0, 0).unwrap().len());
+    /// assert_eq!(2, g.neighborhood_subgraph(0, 1).unwrap().len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the vertex `v` is absent, an error will be returned.
+    pub fn neighborhood_subgraph(&self, v: usize, hops: usize) -> Result<Self> {
+        if !self.alive(v) {
+            return Err(anyhow!("Can't find ν{v}"));
+        }
+        let mut done = HashSet::from([v]);
+        let mut wave = vec![v];
+        let mut depth = 0;
+        while depth < hops && !wave.is_empty() {
+            let mut next = vec![];
+            for u in wave {
+                for (_, to) in self.live_kids(u) {
+                    if done.insert(*to) {
+                        next.push(*to);
+                    }
+                }
             }
-            for (k, v2) in &vtx.edges {
-                if done.contains(v2) {
-                    ng.add(*v2);
-                    ng.bind(v1, *v2, *k);
+            wave = next;
+            depth += 1;
+        }
+        let ng = self.induced(&done);
+        trace!(
+            "#neighborhood_subgraph: taken {} vertices out of {} at ν{v} within {hops} hops",
+            ng.len(),
+            self.len()
+        );
+        Ok(ng)
+    }
+
+    /// Take a slice of the graph, the same way as [`Sodg::slice`] does,
+    /// but renumbering the vertices densely: the vertex `v` becomes `ν0`,
+    /// while all others get the next numbers, in breadth-first order,
+    /// visiting kids in the order of their labels. The capacity of
+    /// the new graph is the number of its vertices. For example:
+    ///
+    /// ```
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(42);
+    /// g.add(7);
+    /// g.bind(42, 7, Label::Alpha(0));
+    /// g.put(7, &Hex::from(1));
+    /// let s = g.slice_dense(42).unwrap();
+    /// assert_eq!(Some(1), s.kid(0, Label::Alpha(0)));
+    /// assert_eq!(2, s.len());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the vertex `v` is absent, an error will be returned.
+    pub fn slice_dense(&self, v: usize) -> Result<Self> {
+        if !self.alive(v) {
+            return Err(anyhow!("Can't find ν{v}"));
+        }
+        let mut ids: HashMap<usize, usize> = HashMap::from([(v, 0)]);
+        let mut order = vec![v];
+        let mut todo = VecDeque::from([v]);
+        while let Some(u) = todo.pop_front() {
+            for (_, to) in self.live_kids(u) {
+                if !ids.contains_key(to) {
+                    ids.insert(*to, order.len());
+                    order.push(*to);
+                    todo.push_back(*to);
                 }
             }
         }
+        let mut ng = Self::empty(order.len());
+        for u in &order {
+            ng.add(ids[u]);
+        }
+        for u in &order {
+            for (a, to) in self.live_kids(*u) {
+                ng.bind(ids[u], ids[to], *a);
+            }
+            if let Some(d) = self.peek(*u) {
+                ng.put(ids[u], d);
+            }
+        }
         trace!(
-            "#slice_some: taken {} vertices out of {} at ν{v}",
+            "#slice_dense: taken {} vertices out of {} at ν{v}",
             ng.len(),
             self.len()
         );
         Ok(ng)
     }
+
+    /// Make a new graph with only these vertices, their data,
+    /// and the edges between them, skipping the vertices that
+    /// were already collected as garbage.
+    pub(crate) fn induced(&self, done: &HashSet<usize>) -> Self {
+        let mut ng = Self::empty(self.vertices.capacity());
+        for (v1, vtx) in self
+            .vertices
+            .iter()
+            .filter(|(v, _)| done.contains(v) && self.alive(*v))
+        {
+            ng.add_if_absent(v1);
+            for (k, v2) in &vtx.edges {
+                if done.contains(v2) && self.alive(*v2) {
+                    ng.add_if_absent(*v2);
+                    ng.bind(v1, *v2, *k);
+                }
+            }
+        }
+        for v1 in done.iter().filter(|v| self.alive(**v)) {
+            if let Some(d) = self.peek(*v1) {
+                ng.put(*v1, d);
+            }
+        }
+        ng
+    }
 }
 
+#[cfg(test)]
+use crate::Hex;
+
 #[cfg(test)]
 use std::str::FromStr;
 
@@ -112,6 +264,17 @@ fn makes_a_slice() {
     assert_eq!(1, g.slice(2).unwrap().len());
 }
 
+#[test]
+fn copies_data_into_slice() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.put(1, &Hex::from(42));
+    let mut slice = g.slice(0).unwrap();
+    assert_eq!(42, slice.data(1).unwrap().to_i64().unwrap());
+}
+
 #[test]
 fn makes_a_partial_slice() {
     let mut g: Sodg<16> = Sodg::empty(256);
@@ -138,3 +301,106 @@ fn skips_some_vertices() {
     assert_eq!(2, slice.len());
     assert_eq!(1, slice.kids(0).count());
 }
+
+#[test]
+fn takes_neighborhoods() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..7 {
+        g.add(v);
+    }
+    for v in 0..3 {
+        g.bind(v, 2 * v + 1, Label::Alpha(0));
+        g.bind(v, 2 * v + 2, Label::Alpha(1));
+    }
+    g.bind(0, 6, Label::Alpha(2));
+    assert_eq!(4, g.neighborhood_subgraph(0, 1).unwrap().len());
+    assert_eq!(7, g.neighborhood_subgraph(0, 2).unwrap().len());
+    g.neighborhood_subgraph(1, usize::MAX)
+        .unwrap()
+        .assert_structurally_equal(&g.slice(1).unwrap());
+    assert!(g.neighborhood_subgraph(42, 1).is_err());
+}
+
+#[test]
+fn leaves_collected_vertices_out_of_neighborhoods() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let n = g.neighborhood_subgraph(0, 2).unwrap();
+    assert_eq!(2, n.len());
+    assert_eq!(None, n.kid(0, Label::Alpha(1)));
+    assert_eq!(2, g.slice(0).unwrap().len());
+}
+
+#[test]
+fn slices_by_label() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    let rho = Label::Greek('ρ');
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(1, 3, Label::Alpha(1));
+    g.bind(2, 1, rho);
+    g.bind(1, 0, rho);
+    g.bind(3, 4, rho);
+    let s = g.slice_by_label(1, |a| *a != rho).unwrap();
+    let mut keys = s.keys();
+    keys.sort_unstable();
+    assert_eq!(vec![1, 2, 3], keys);
+    assert_eq!(Some(1), s.kid(2, rho));
+    assert_eq!(None, s.kid(1, rho));
+    assert_eq!(5, g.slice_by_label(1, |_| true).unwrap().len());
+}
+
+#[test]
+fn makes_a_dense_slice() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in [10, 20, 30, 40, 50] {
+        g.add(v);
+    }
+    g.bind(20, 30, Label::Alpha(1));
+    g.bind(20, 40, Label::Alpha(0));
+    g.bind(40, 10, Label::Alpha(0));
+    g.bind(10, 20, Label::Alpha(0));
+    g.bind(50, 20, Label::Alpha(0));
+    g.put(30, &crate::Hex::from_str_bytes("hi"));
+    let s = g.slice_dense(20).unwrap();
+    assert_eq!(4, s.len());
+    assert_eq!(vec![0, 1, 2, 3], {
+        let mut keys = s.keys();
+        keys.sort_unstable();
+        keys
+    });
+    assert_eq!(Some(1), s.kid(0, Label::Alpha(0)));
+    assert_eq!(Some(2), s.kid(0, Label::Alpha(1)));
+    assert_eq!(Some(3), s.kid(1, Label::Alpha(0)));
+    assert_eq!(Some(0), s.kid(3, Label::Alpha(0)));
+    assert_eq!(b"hi".to_vec(), s.peek(2).unwrap().to_vec());
+    assert!(s.same_as(&g, 0, 20));
+    assert!(g.slice_dense(60).is_err());
+}
+
+#[test]
+fn leaves_collected_vertices_out_of_dense_slice() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    let s = g.slice_dense(0).unwrap();
+    assert_eq!(2, s.len());
+    assert_eq!(Some(1), s.kid(0, Label::Alpha(0)));
+    assert_eq!(None, s.kid(0, Label::Alpha(1)));
+}
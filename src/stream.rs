@@ -0,0 +1,227 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Persistence, Sodg};
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::io::{BufRead, Write};
+use std::str::FromStr;
+
+/// A single vertex, as it is streamed in JSON.
+#[derive(Serialize, Deserialize)]
+pub struct VertexJson {
+    pub id: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<String>,
+    #[serde(default)]
+    pub edges: Vec<EdgeJson>,
+}
+
+/// A single edge of a vertex, as it is streamed in JSON.
+#[derive(Serialize, Deserialize)]
+pub struct EdgeJson {
+    pub a: String,
+    pub to: usize,
+}
+
+impl<const N: usize> Sodg<N> {
+    /// Write all vertices to the `writer`, in batches of `batch_size`.
+    ///
+    /// Each batch is a JSON array of vertices, terminated by a newline,
+    /// which makes the output suitable for chunked transfer, for example
+    /// through a WebSocket. The function returns the total number of
+    /// batches written. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Hex, Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// g.put(1, &Hex::from(42));
+    /// let mut buf = vec![];
+    /// assert_eq!(1, g.stream_vertices(&mut buf, 10).unwrap());
+    /// assert_eq!(
+    ///     "[{\"id\":0,\"edges\":[{\"a\":\"foo\",\"to\":1}]},\
+    ///     {\"id\":1,\"data\":\"00-00-00-00-00-00-00-2A\",\"edges\":[]}]\n",
+    ///     String::from_utf8(buf).unwrap()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `batch_size` is zero or the writer fails, an error will be returned.
+    pub fn stream_vertices<W: Write>(&self, writer: &mut W, batch_size: usize) -> Result<usize> {
+        if batch_size == 0 {
+            return Err(anyhow!("The size of a batch can't be zero"));
+        }
+        let all: Vec<VertexJson> = self.keys().into_iter().map(|v| self.v_json(v)).collect();
+        let mut total = 0;
+        for batch in all.chunks(batch_size) {
+            serde_json::to_writer(&mut *writer, batch)
+                .with_context(|| format!("Can't write batch no.{total}"))?;
+            writer.write_all(b"\n")?;
+            total += 1;
+        }
+        Ok(total)
+    }
+
+    /// Build a new [`Sodg`] from JSON-lines batches, as they are
+    /// produced by [`Sodg::stream_vertices`].
+    ///
+    /// Batches are applied one by one, as soon as they are read from the `reader`,
+    /// so the graph grows while the data arrives. The `cap` is the capacity
+    /// of the graph, as in [`Sodg::empty`]. For example:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::from_str("foo").unwrap());
+    /// let mut buf = vec![];
+    /// g.stream_vertices(&mut buf, 1).unwrap();
+    /// let after : Sodg<16> = Sodg::from_stream_vertices(buf.as_slice(), 256).unwrap();
+    /// assert_eq!(1, after.kid(0, Label::from_str("foo").unwrap()).unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the stream can't be read or parsed, or an ID of a vertex
+    /// doesn't fit into the `cap`, an error will be returned.
+    pub fn from_stream_vertices<R: BufRead>(reader: R, cap: usize) -> Result<Self> {
+        let mut g = Self::empty(cap);
+        for (pos, line) in reader.lines().enumerate() {
+            let line = line.with_context(|| format!("Can't read line no.{}", pos + 1))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let batch: Vec<VertexJson> = serde_json::from_str(&line)
+                .with_context(|| format!("Can't parse batch at line no.{}", pos + 1))?;
+            for vtx in batch {
+                g.v_from_json(&vtx)?;
+            }
+        }
+        Ok(g)
+    }
+
+    /// Put a single vertex, previously streamed in JSON, into the graph.
+    ///
+    /// If the vertex or any of its kids doesn't fit into the capacity
+    /// of the graph, an error is returned.
+    pub(crate) fn v_from_json(&mut self, vtx: &VertexJson) -> Result<()> {
+        self.try_add_if_absent(vtx.id)?;
+        if let Some(d) = &vtx.data {
+            self.put(
+                vtx.id,
+                &Hex::from_str(d).with_context(|| format!("Can't parse data of ν{}", vtx.id))?,
+            );
+        }
+        for e in &vtx.edges {
+            self.try_add_if_absent(e.to)?;
+            self.bind(vtx.id, e.to, Label::from_str(&e.a)?);
+        }
+        Ok(())
+    }
+
+    /// Make a JSON-friendly representation of a single vertex.
+    pub(crate) fn v_json(&self, v: usize) -> VertexJson {
+        let vtx = self.vertices.get(v).unwrap();
+        let mut edges: Vec<EdgeJson> = vtx
+            .edges
+            .iter()
+            .map(|(a, to)| EdgeJson {
+                a: a.to_string(),
+                to: *to,
+            })
+            .collect();
+        edges.sort_by(|x, y| x.a.cmp(&y.a));
+        VertexJson {
+            id: v,
+            data: if vtx.persistence == Persistence::Empty {
+                None
+            } else {
+                Some(vtx.data.print())
+            },
+            edges,
+        }
+    }
+}
+
+#[test]
+fn streams_in_batches() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.put(2, &Hex::from_str_bytes("hello"));
+    let mut buf = vec![];
+    assert_eq!(3, g.stream_vertices(&mut buf, 2).unwrap());
+    let txt = String::from_utf8(buf).unwrap();
+    let lines: Vec<&str> = txt.lines().collect();
+    assert_eq!(3, lines.len());
+    let last: Vec<serde_json::Value> = serde_json::from_str(lines[2]).unwrap();
+    assert_eq!(1, last.len());
+    assert_eq!(4, last[0]["id"]);
+}
+
+#[test]
+fn rejects_zero_batch() {
+    let g: Sodg<16> = Sodg::empty(256);
+    assert!(g.stream_vertices(&mut vec![], 0).is_err());
+}
+
+#[test]
+fn reads_what_it_streams() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    g.bind(1, 2, Label::from_str("bar").unwrap());
+    g.bind(2, 0, Label::from_str("back").unwrap());
+    g.put(2, &Hex::from_str_bytes("hello"));
+    let mut buf = vec![];
+    g.stream_vertices(&mut buf, 1).unwrap();
+    let after: Sodg<16> = Sodg::from_stream_vertices(buf.as_slice(), 256).unwrap();
+    assert_eq!(g.inspect(0).unwrap(), after.inspect(0).unwrap());
+    assert_eq!(3, after.len());
+}
+
+#[test]
+fn fails_on_broken_stream() {
+    let r: Result<Sodg<16>> = Sodg::from_stream_vertices(b"[{\"id\":".as_slice(), 256);
+    assert!(r.is_err());
+}
+
+#[test]
+fn rejects_too_big_vertex() {
+    let r: Result<Sodg<16>> =
+        Sodg::from_stream_vertices(&b"[{\"id\":100000000,\"edges\":[]}]\n"[..], 16);
+    assert!(r.is_err());
+    let r: Result<Sodg<16>> = Sodg::from_stream_vertices(
+        &b"[{\"id\":0,\"edges\":[{\"a\":\"foo\",\"to\":16}]}]\n"[..],
+        16,
+    );
+    assert!(r.is_err());
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, MergeConflict, Sodg};
+use anyhow::Result;
+use std::collections::BTreeSet;
+
+impl<const N: usize> Sodg<N> {
+    /// Merge changes made in `theirs`, relative to their common ancestor
+    /// `base`, into the current graph, the way `git merge` does.
+    ///
+    /// All three graphs are expected to use the same IDs of vertices.
+    /// Only vertices reachable from the `root` in `base` or in `theirs` are
+    /// compared. An edge or data changed only in `theirs` is taken from
+    /// there. An edge or data changed differently on both sides is not
+    /// touched and is reported as a conflict. Data removed in `theirs`
+    /// is not removed. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut base : Sodg<16> = Sodg::empty(256);
+    /// base.add(0);
+    /// let mut ours = base.clone();
+    /// let mut theirs = base.clone();
+    /// theirs.add(1);
+    /// theirs.bind(0, 1, Label::Alpha(0));
+    /// let conflicts = ours.merge3(&base, &theirs, 0).unwrap();
+    /// assert!(conflicts.is_empty());
+    /// assert_eq!(Some(1), ours.kid(0, Label::Alpha(0)));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `root` is absent in `base` or in `theirs`,
+    /// an error will be returned.
+    pub fn merge3(
+        &mut self,
+        base: &Self,
+        theirs: &Self,
+        root: usize,
+    ) -> Result<Vec<MergeConflict>> {
+        let scope: BTreeSet<usize> = base
+            .reachable_from(root)?
+            .into_iter()
+            .chain(theirs.reachable_from(root)?)
+            .collect();
+        for v in &scope {
+            if theirs.alive(*v) && !base.alive(*v) {
+                self.add_if_absent(*v);
+            }
+        }
+        let mut conflicts = vec![];
+        for v in scope {
+            let labels: BTreeSet<Label> = Self::edges_of(base, v)
+                .chain(Self::edges_of(theirs, v))
+                .collect();
+            for a in labels {
+                let b = Self::edge_in(base, v, a);
+                let t = Self::edge_in(theirs, v, a);
+                let o = Self::edge_in(self, v, a);
+                if b == t || o == t {
+                    continue;
+                }
+                if o != b {
+                    conflicts.push(MergeConflict::Edge {
+                        vertex: v,
+                        label: a,
+                        base: b,
+                        ours: o,
+                        theirs: t,
+                    });
+                    continue;
+                }
+                self.add_if_absent(v);
+                match t {
+                    Some(to) => {
+                        self.add_if_absent(to);
+                        self.bind(v, to, a);
+                    }
+                    None => {
+                        self.unbind(v, a);
+                    }
+                }
+            }
+            let b = Self::data_in(base, v);
+            let t = Self::data_in(theirs, v);
+            let o = Self::data_in(self, v);
+            if t.is_none() || b == t || o == t {
+                continue;
+            }
+            if o != b {
+                conflicts.push(MergeConflict::Data { vertex: v });
+                continue;
+            }
+            if let Some(d) = t {
+                self.add_if_absent(v);
+                self.put(v, d);
+            }
+        }
+        Ok(conflicts)
+    }
+
+    /// Labels of all edges of the vertex, if it is alive.
+    fn edges_of(g: &Self, v: usize) -> impl Iterator<Item = Label> + '_ {
+        g.alive(v)
+            .then(|| g.kids(v).map(|(a, _)| *a))
+            .into_iter()
+            .flatten()
+    }
+
+    /// The kid of the vertex, if the vertex is alive.
+    fn edge_in(g: &Self, v: usize, a: Label) -> Option<usize> {
+        g.alive(v).then(|| g.kid(v, a)).flatten()
+    }
+
+    /// The data of the vertex, if the vertex is alive.
+    fn data_in(g: &Self, v: usize) -> Option<&Hex> {
+        g.alive(v).then(|| g.peek(v)).flatten()
+    }
+}
+
+#[cfg(test)]
+fn common() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..3 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.put(1, &Hex::from(1));
+    g
+}
+
+#[test]
+fn applies_their_changes() {
+    let base = common();
+    let mut ours = base.clone();
+    ours.add(3);
+    ours.bind(2, 3, Label::Alpha(0));
+    let mut theirs = base.clone();
+    theirs.add(7);
+    theirs.bind(1, 7, Label::Alpha(0));
+    theirs.bind(0, 2, Label::Alpha(5));
+    theirs.unbind(0, Label::Alpha(1));
+    theirs.put(1, &Hex::from(42));
+    theirs.put(7, &Hex::from(7));
+    assert!(ours.merge3(&base, &theirs, 0).unwrap().is_empty());
+    assert_eq!(Some(7), ours.kid(1, Label::Alpha(0)));
+    assert_eq!(Some(3), ours.kid(2, Label::Alpha(0)));
+    assert_eq!(Some(2), ours.kid(0, Label::Alpha(5)));
+    assert_eq!(None, ours.kid(0, Label::Alpha(1)));
+    assert_eq!(42, ours.peek(1).unwrap().to_i64().unwrap());
+    assert_eq!(7, ours.peek(7).unwrap().to_i64().unwrap());
+}
+
+#[test]
+fn reports_conflicts() {
+    let base = common();
+    let mut ours = base.clone();
+    ours.add(3);
+    ours.bind(0, 3, Label::Alpha(0));
+    ours.put(2, &Hex::from(2));
+    let mut theirs = base.clone();
+    theirs.add(4);
+    theirs.bind(0, 4, Label::Alpha(0));
+    theirs.put(2, &Hex::from(-2));
+    theirs.bind(0, 1, Label::Alpha(9));
+    let conflicts = ours.merge3(&base, &theirs, 0).unwrap();
+    assert_eq!(
+        vec![
+            MergeConflict::Edge {
+                vertex: 0,
+                label: Label::Alpha(0),
+                base: Some(1),
+                ours: Some(3),
+                theirs: Some(4),
+            },
+            MergeConflict::Data { vertex: 2 },
+        ],
+        conflicts
+    );
+    assert_eq!(Some(3), ours.kid(0, Label::Alpha(0)));
+    assert_eq!(Some(1), ours.kid(0, Label::Alpha(9)));
+    assert_eq!(2, ours.peek(2).unwrap().to_i64().unwrap());
+}
@@ -0,0 +1,599 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Result};
+use std::cmp::Reverse;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet};
+
+impl<const N: usize> Sodg<N> {
+    /// Sort all vertices reachable from the `root` topologically, breaking
+    /// ties by the `priority` of each vertex.
+    ///
+    /// Vertices are taken in waves: first, the `root`, then all vertices
+    /// that have no more incoming edges from unsorted vertices, and so on.
+    /// Inside each wave vertices are ordered by their priority, ascending,
+    /// and then by their IDs. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 2, Label::Alpha(1));
+    /// let order = g.topological_sort_with_priority(0, |v| -(v as i64)).unwrap();
+    /// assert_eq!(vec![0, 2, 1], order);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If the `root` is absent or there is a cycle reachable from it,
+    /// an error will be returned.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the graph is broken (should never happen, though).
+    pub fn topological_sort_with_priority<F: Fn(usize) -> i64>(
+        &self,
+        root: usize,
+        priority: F,
+    ) -> Result<Vec<usize>> {
+        let reachable = self.reachable_from(root)?;
+        let mut degrees: HashMap<usize, usize> = reachable.iter().map(|v| (*v, 0)).collect();
+        for v in &reachable {
+            for (_, to) in self.live_kids(*v) {
+                *degrees.get_mut(to).unwrap() += 1;
+            }
+        }
+        let mut order = Vec::with_capacity(reachable.len());
+        let mut wave: Vec<usize> = degrees
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(v, _)| *v)
+            .collect();
+        while !wave.is_empty() {
+            wave.sort_by_key(|v| (priority(*v), *v));
+            let mut next = vec![];
+            for v in &wave {
+                for (_, to) in self.live_kids(*v) {
+                    let d = degrees.get_mut(to).unwrap();
+                    *d -= 1;
+                    if *d == 0 {
+                        next.push(*to);
+                    }
+                }
+            }
+            order.append(&mut wave);
+            wave = next;
+        }
+        if order.len() < reachable.len() {
+            return Err(anyhow!("There is a cycle reachable from ν{root}"));
+        }
+        Ok(order)
+    }
+
+    /// Sort all vertices of the graph topologically, making sure the result
+    /// is the same on every run for the same graph.
+    ///
+    /// Among all vertices ready to be taken, the one with the smallest ID
+    /// is always taken first. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(3);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(3, 1, Label::Alpha(0));
+    /// assert_eq!(vec![2, 3, 1], g.topological_sort_stable().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is a cycle in the graph, an error will be returned.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the graph is broken (should never happen, though).
+    pub fn topological_sort_stable(&self) -> Result<Vec<usize>> {
+        let mut degrees = self.in_degrees();
+        let mut ready: BTreeSet<usize> = degrees
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(v, _)| *v)
+            .collect();
+        let mut order = Vec::with_capacity(degrees.len());
+        while let Some(v) = ready.pop_first() {
+            order.push(v);
+            for (_, to) in self.live_kids(v) {
+                let d = degrees.get_mut(to).unwrap();
+                *d -= 1;
+                if *d == 0 {
+                    ready.insert(*to);
+                }
+            }
+        }
+        if order.len() < degrees.len() {
+            return Err(anyhow!(
+                "There is a cycle in the graph, {} vertices can't be sorted",
+                degrees.len() - order.len()
+            ));
+        }
+        Ok(order)
+    }
+
+    /// Check whether there is at least one directed cycle in the graph.
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::chain(3, Label::Alpha(0)).unwrap();
+    /// assert!(!g.has_cycle());
+    /// g.bind(2, 0, Label::Alpha(0));
+    /// assert!(g.has_cycle());
+    /// ```
+    #[must_use]
+    pub fn has_cycle(&self) -> bool {
+        self.find_cycle().is_some()
+    }
+
+    /// Sort all vertices of the graph topologically, the same way as
+    /// [`Sodg::topological_sort_stable`] does, but reporting the cycle,
+    /// if there is one. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::chain(3, Label::Alpha(0)).unwrap();
+    /// assert_eq!(vec![0, 1, 2], g.toposort().unwrap());
+    /// g.bind(2, 1, Label::Alpha(1));
+    /// assert_eq!(
+    ///     "There is a cycle in the graph: ν1 → ν2 → ν1",
+    ///     g.toposort().unwrap_err().to_string()
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is a cycle in the graph, an error will be returned,
+    /// listing the vertices of one of the cycles.
+    pub fn toposort(&self) -> Result<Vec<usize>> {
+        if let Some(cycle) = self.find_cycle() {
+            return Err(anyhow!(
+                "There is a cycle in the graph: {} → ν{}",
+                cycle
+                    .iter()
+                    .map(|v| format!("ν{v}"))
+                    .collect::<Vec<_>>()
+                    .join(" → "),
+                cycle[0]
+            ));
+        }
+        self.topological_sort_stable()
+    }
+
+    /// Find a directed cycle, returning its vertices in the order of
+    /// the edges, starting from the vertex where the search entered it.
+    fn find_cycle(&self) -> Option<Vec<usize>> {
+        let targets = |v: usize| -> Vec<usize> {
+            let mut kids: Vec<usize> = self.live_kids(v).map(|(_, to)| *to).collect();
+            kids.reverse();
+            kids
+        };
+        let mut keys = self.keys();
+        keys.sort_unstable();
+        let mut done: HashSet<usize> = HashSet::new();
+        for root in keys {
+            if done.contains(&root) {
+                continue;
+            }
+            let mut path: Vec<usize> = vec![root];
+            let mut on_path: HashSet<usize> = HashSet::from([root]);
+            let mut stack: Vec<Vec<usize>> = vec![targets(root)];
+            while let Some(kids) = stack.last_mut() {
+                let Some(k) = kids.pop() else {
+                    stack.pop();
+                    let v = path.pop().unwrap();
+                    on_path.remove(&v);
+                    done.insert(v);
+                    continue;
+                };
+                if on_path.contains(&k) {
+                    let pos = path.iter().position(|v| *v == k).unwrap();
+                    return Some(path[pos..].to_vec());
+                }
+                if done.contains(&k) {
+                    continue;
+                }
+                path.push(k);
+                on_path.insert(k);
+                stack.push(targets(k));
+            }
+        }
+        None
+    }
+
+    /// Sort all vertices of the graph in the reverse topological order,
+    /// which is exactly the reverse of [`Sodg::topological_sort_stable`].
+    ///
+    /// Vertices without kids go first. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let g : Sodg<16> = Sodg::chain(3, Label::Alpha(0)).unwrap();
+    /// assert_eq!(vec![2, 1, 0], g.topological_sort_reverse().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is a cycle in the graph, an error will be returned.
+    pub fn topological_sort_reverse(&self) -> Result<Vec<usize>> {
+        let mut order = self.topological_sort_stable()?;
+        order.reverse();
+        Ok(order)
+    }
+
+    /// Sort all vertices of the graph topologically, taking the vertex
+    /// with the highest priority first, among all vertices ready to be taken.
+    ///
+    /// Vertices missing in `priorities` have the priority of zero.
+    /// Ties are broken by IDs, the smallest first. For example:
+    ///
+    /// ```
+    /// use std::collections::HashMap;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// let order = g.topological_order_with_priorities(&HashMap::from([(2, -1)])).unwrap();
+    /// assert_eq!(vec![0, 1, 2], order);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is a cycle in the graph, an error will be returned.
+    ///
+    /// # Panics
+    ///
+    /// May panic if the graph is broken (should never happen, though).
+    pub fn topological_order_with_priorities(
+        &self,
+        priorities: &HashMap<usize, i64>,
+    ) -> Result<Vec<usize>> {
+        let priority = |v: usize| (priorities.get(&v).copied().unwrap_or(0), Reverse(v));
+        let mut degrees = self.in_degrees();
+        let mut ready: BinaryHeap<(i64, Reverse<usize>)> = degrees
+            .iter()
+            .filter(|(_, d)| **d == 0)
+            .map(|(v, _)| priority(*v))
+            .collect();
+        let mut order = Vec::with_capacity(degrees.len());
+        while let Some((_, Reverse(v))) = ready.pop() {
+            order.push(v);
+            for (_, to) in self.live_kids(v) {
+                let d = degrees.get_mut(to).unwrap();
+                *d -= 1;
+                if *d == 0 {
+                    ready.push(priority(*to));
+                }
+            }
+        }
+        if order.len() < degrees.len() {
+            return Err(anyhow!(
+                "There is a cycle in the graph, {} vertices can't be sorted",
+                degrees.len() - order.len()
+            ));
+        }
+        Ok(order)
+    }
+
+    /// Give each vertex of a DAG its level: zero to vertices without
+    /// incoming edges, and one plus the biggest level of its
+    /// predecessors to all others. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(0, 2, Label::Alpha(1));
+    /// assert_eq!(2, g.topological_rank().unwrap()[&2]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is a cycle in the graph, an error will be returned.
+    pub fn topological_rank(&self) -> Result<HashMap<usize, usize>> {
+        let order = self.topological_sort_stable()?;
+        let mut ranks: HashMap<usize, usize> = order.iter().map(|v| (*v, 0)).collect();
+        for v in order {
+            let r = ranks[&v] + 1;
+            for (_, to) in self.live_kids(v) {
+                let t = ranks.entry(*to).or_default();
+                *t = (*t).max(r);
+            }
+        }
+        Ok(ranks)
+    }
+
+    /// Count the levels of a DAG, as they are given by
+    /// [`Sodg::topological_rank`], which is one more than the length
+    /// of the longest path. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// assert_eq!(0, g.dag_depth().unwrap());
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// assert_eq!(2, g.dag_depth().unwrap());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// If there is a cycle in the graph, an error will be returned.
+    pub fn dag_depth(&self) -> Result<usize> {
+        Ok(self.topological_rank()?.values().max().map_or(0, |r| r + 1))
+    }
+
+    /// Find all vertices reachable from the given one, including itself.
+    pub(crate) fn reachable_from(&self, root: usize) -> Result<HashSet<usize>> {
+        if !self.alive(root) {
+            return Err(anyhow!("Can't find ν{root}"));
+        }
+        let mut seen = HashSet::from([root]);
+        let mut todo = vec![root];
+        while let Some(v) = todo.pop() {
+            for (_, to) in self.live_kids(v) {
+                if seen.insert(*to) {
+                    todo.push(*to);
+                }
+            }
+        }
+        Ok(seen)
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[test]
+fn sorts_by_priority_inside_waves() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(0, 3, Label::Alpha(2));
+    g.bind(3, 4, Label::Alpha(0));
+    g.bind(1, 4, Label::Alpha(0));
+    let order = g
+        .topological_sort_with_priority(0, |v| [0, 5, 1, 3, 0][v])
+        .unwrap();
+    assert_eq!(vec![0, 2, 3, 1, 4], order);
+}
+
+#[test]
+fn fails_on_cycle() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 0, Label::Alpha(0));
+    assert!(g.topological_sort_with_priority(0, |_| 0).is_err());
+}
+
+#[test]
+fn sorts_the_same_way_every_time() {
+    let mut first: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        first.add(v);
+    }
+    let edges = [(5, 2), (5, 0), (4, 0), (4, 1), (2, 3), (3, 1)];
+    for (i, (v1, v2)) in edges.iter().enumerate() {
+        first.bind(*v1, *v2, Label::Alpha(i));
+    }
+    let order = first.topological_sort_stable().unwrap();
+    assert_eq!(vec![4, 5, 0, 2, 3, 1], order);
+    for _ in 0..100 {
+        assert_eq!(order, first.topological_sort_stable().unwrap());
+    }
+    let mut second: Sodg<16> = Sodg::empty(256);
+    for v in (0..6).rev() {
+        second.add(v);
+    }
+    for (i, (v1, v2)) in edges.iter().enumerate().rev() {
+        second.bind(*v1, *v2, Label::Alpha(i));
+    }
+    assert_eq!(order, second.topological_sort_stable().unwrap());
+    for (v1, v2) in edges {
+        let p1 = order.iter().position(|v| *v == v1).unwrap();
+        let p2 = order.iter().position(|v| *v == v2).unwrap();
+        assert!(p1 < p2);
+    }
+}
+
+#[test]
+fn ranks_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..7 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(4, 5, Label::Alpha(0));
+    g.bind(5, 3, Label::Alpha(0));
+    let ranks = g.topological_rank().unwrap();
+    assert_eq!(
+        vec![0, 1, 1, 2, 0, 1, 0],
+        (0..7).map(|v| ranks[&v]).collect::<Vec<usize>>()
+    );
+    g.bind(3, 0, Label::Alpha(0));
+    assert!(g.topological_rank().is_err());
+}
+
+#[test]
+fn ranks_chain() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    for v in 0..4 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    let ranks = g.topological_rank().unwrap();
+    assert!((0..5).all(|v| ranks[&v] == v));
+}
+
+#[test]
+fn sorts_by_priorities() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..6 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(1, 2, Label::Alpha(0));
+    g.bind(3, 4, Label::Alpha(0));
+    g.bind(2, 5, Label::Alpha(0));
+    g.bind(4, 5, Label::Alpha(0));
+    let order = g
+        .topological_order_with_priorities(&HashMap::from([(3, 10), (4, 5), (1, -1)]))
+        .unwrap();
+    assert_eq!(vec![3, 4, 0, 1, 2, 5], order);
+    let pos: HashMap<usize, usize> = order.iter().enumerate().map(|(i, v)| (*v, i)).collect();
+    for v in 0..6 {
+        for (_, to) in g.kids(v) {
+            assert!(pos[&v] < pos[to]);
+        }
+    }
+    assert_eq!(
+        g.topological_sort_stable().unwrap(),
+        g.topological_order_with_priorities(&HashMap::new())
+            .unwrap()
+    );
+    g.bind(5, 0, Label::Alpha(0));
+    assert!(g
+        .topological_order_with_priorities(&HashMap::new())
+        .is_err());
+}
+
+#[test]
+fn measures_dag_depth() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    assert_eq!(1, g.dag_depth().unwrap());
+    for v in 1..5 {
+        g.add(v);
+        g.bind(v - 1, v, Label::Alpha(0));
+    }
+    assert_eq!(5, g.dag_depth().unwrap());
+    let mut d: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        d.add(v);
+    }
+    d.bind(0, 1, Label::Alpha(0));
+    d.bind(0, 2, Label::Alpha(1));
+    d.bind(1, 3, Label::Alpha(0));
+    d.bind(2, 3, Label::Alpha(0));
+    assert_eq!(3, d.dag_depth().unwrap());
+    d.bind(3, 0, Label::Alpha(0));
+    assert!(d.dag_depth().is_err());
+}
+
+#[test]
+fn sorts_in_reverse() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    let order = g.topological_sort_reverse().unwrap();
+    assert_eq!(3, order[0]);
+    let mut stable = g.topological_sort_stable().unwrap();
+    stable.reverse();
+    assert_eq!(stable, order);
+    g.bind(3, 0, Label::Alpha(0));
+    assert!(g.topological_sort_reverse().is_err());
+}
+
+#[test]
+fn finds_cycles() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(2, 1, Label::Alpha(0));
+    g.bind(1, 3, Label::Alpha(0));
+    assert!(!g.has_cycle());
+    assert_eq!(vec![0, 2, 1, 3, 4], g.toposort().unwrap());
+    g.bind(3, 4, Label::Alpha(0));
+    g.bind(4, 2, Label::Alpha(0));
+    assert!(g.has_cycle());
+    assert_eq!(
+        "There is a cycle in the graph: ν1 → ν3 → ν4 → ν2 → ν1",
+        g.toposort().unwrap_err().to_string()
+    );
+}
+
+#[test]
+fn ignores_collected_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(0, 3, Label::Alpha(1));
+    g.bind(3, 2, Label::Alpha(1));
+    g.put(3, &Hex::from(42));
+    g.data(3);
+    assert_eq!(vec![0, 1], g.toposort().unwrap());
+    assert!(!g.has_cycle());
+    assert_eq!(vec![1, 0], g.topological_sort_reverse().unwrap());
+    assert_eq!(
+        vec![0, 1],
+        g.topological_order_with_priorities(&HashMap::new())
+            .unwrap()
+    );
+    let ranks = g.topological_rank().unwrap();
+    assert_eq!(2, ranks.len());
+    assert_eq!(2, g.dag_depth().unwrap());
+    assert_eq!(vec![vec![0], vec![1]], {
+        let mut sccs = g.sccs();
+        sccs.sort();
+        sccs
+    });
+}
@@ -0,0 +1,112 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Label, Sodg};
+use std::cmp::Reverse;
+
+impl<const N: usize> Sodg<N> {
+    /// Start counting how many times each edge is passed by [`Sodg::kid`].
+    ///
+    /// When tracing is off (which is the default), [`Sodg::kid`] doesn't
+    /// spend any time on counting.
+    pub const fn enable_edge_tracing(&mut self) {
+        self.tracing = true;
+    }
+
+    /// Stop counting edges, keeping the counters collected so far.
+    pub const fn disable_edge_tracing(&mut self) {
+        self.tracing = false;
+    }
+
+    /// Report how many times each edge was passed while tracing was on,
+    /// the most frequent edges first. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.enable_edge_tracing();
+    /// assert!(g.kid(0, Label::Alpha(0)).is_some());
+    /// assert!(g.kid(0, Label::Alpha(0)).is_some());
+    /// assert_eq!(vec![((0, "α0".to_string()), 2)], g.edge_frequency_report());
+    /// ```
+    #[must_use]
+    pub fn edge_frequency_report(&self) -> Vec<((usize, String), usize)> {
+        let mut report: Vec<((usize, Label), usize)> =
+            self.traces.borrow().iter().map(|(e, c)| (*e, *c)).collect();
+        report.sort_by_key(|(e, c)| (Reverse(*c), *e));
+        report
+            .into_iter()
+            .map(|((v, a), c)| ((v, a.to_string()), c))
+            .collect()
+    }
+
+    /// Count one pass through an edge.
+    pub(crate) fn trace_edge(&self, v: usize, a: Label) {
+        *self.traces.borrow_mut().entry((v, a)).or_default() += 1;
+    }
+}
+
+#[cfg(test)]
+fn walk(g: &Sodg<16>) {
+    for _ in 0..10 {
+        let mut v = 0;
+        while let Some(k) = g.kid(v, Label::Alpha(0)) {
+            v = k;
+        }
+        assert_eq!(Some(3), g.kid(0, Label::Alpha(1)));
+    }
+}
+
+#[cfg(test)]
+fn chain() -> Sodg<16> {
+    let mut g = Sodg::empty(256);
+    for v in 0..4 {
+        g.add(v);
+    }
+    for v in 0..3 {
+        g.bind(v, v + 1, Label::Alpha(0));
+    }
+    g.bind(0, 3, Label::Alpha(1));
+    g
+}
+
+#[test]
+fn counts_passed_edges() {
+    let mut g = chain();
+    g.enable_edge_tracing();
+    walk(&g);
+    let report = g.edge_frequency_report();
+    assert_eq!(4, report.len());
+    assert!(report.iter().all(|(_, c)| *c == 10));
+    assert_eq!((0, "α0".to_string()), report[0].0);
+    g.disable_edge_tracing();
+    walk(&g);
+    assert_eq!(report, g.edge_frequency_report());
+}
+
+#[test]
+fn counts_nothing_without_tracing() {
+    let g = chain();
+    walk(&g);
+    assert!(g.edge_frequency_report().is_empty());
+}
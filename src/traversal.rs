@@ -0,0 +1,156 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Bfs, Dfs, Label, Sodg};
+use std::collections::{HashSet, VecDeque};
+
+impl<const N: usize> Sodg<N> {
+    /// Walk through all vertices reachable from `start`, lazily, in
+    /// breadth-first order, returning each of them once as a tuple
+    /// of the vertex, its depth, and the label of the edge it was
+    /// reached by (`None` for the `start`). For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(2, 0, Label::Alpha(0));
+    /// let all: Vec<(usize, usize, Option<Label>)> = g.bfs(0).collect();
+    /// assert_eq!(vec![(0, 0, None), (1, 1, Some(Label::Alpha(0))), (2, 2, Some(Label::Alpha(0)))], all);
+    /// ```
+    ///
+    /// If there is no `start` vertex, nothing is returned.
+    #[must_use]
+    pub fn bfs(&self, start: usize) -> Bfs<'_, N> {
+        let mut bfs = Bfs {
+            g: self,
+            queue: VecDeque::new(),
+            seen: HashSet::new(),
+        };
+        if self.alive(start) {
+            bfs.queue.push_back((start, 0, None));
+            bfs.seen.insert(start);
+        }
+        bfs
+    }
+
+    /// Walk through all vertices reachable from `start`, lazily, in
+    /// depth-first order, visiting kids in the order of their labels,
+    /// the same way as [`Sodg::bfs`] does. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(0, 2, Label::Alpha(1));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// let order: Vec<(usize, usize)> = g.dfs(0).map(|(v, d, _)| (v, d)).collect();
+    /// assert_eq!(vec![(0, 0), (1, 1), (2, 2)], order);
+    /// ```
+    #[must_use]
+    pub fn dfs(&self, start: usize) -> Dfs<'_, N> {
+        let mut dfs = Dfs {
+            g: self,
+            stack: vec![],
+            seen: HashSet::new(),
+        };
+        if self.alive(start) {
+            dfs.stack.push((start, 0, None));
+        }
+        dfs
+    }
+}
+
+impl<const N: usize> Iterator for Bfs<'_, N> {
+    type Item = (usize, usize, Option<Label>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (v, depth, a) = self.queue.pop_front()?;
+        for (b, to) in self.g.kids(v) {
+            if self.seen.insert(*to) {
+                self.queue.push_back((*to, depth + 1, Some(*b)));
+            }
+        }
+        Some((v, depth, a))
+    }
+}
+
+impl<const N: usize> Iterator for Dfs<'_, N> {
+    type Item = (usize, usize, Option<Label>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some((v, depth, a)) = self.stack.pop() {
+            if !self.seen.insert(v) {
+                continue;
+            }
+            let mut kids: Vec<(usize, usize, Option<Label>)> = self
+                .g
+                .kids(v)
+                .filter(|(_, to)| !self.seen.contains(*to))
+                .map(|(b, to)| (*to, depth + 1, Some(*b)))
+                .collect();
+            kids.reverse();
+            self.stack.extend(kids);
+            return Some((v, depth, a));
+        }
+        None
+    }
+}
+
+#[test]
+fn walks_breadth_first() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(2, 3, Label::Alpha(0));
+    g.bind(3, 0, Label::Alpha(0));
+    let all: Vec<(usize, usize)> = g.bfs(0).map(|(v, d, _)| (v, d)).collect();
+    assert_eq!(vec![(0, 0), (1, 1), (2, 1), (3, 2)], all);
+    assert_eq!(Some((3, 1, Some(Label::Alpha(0)))), g.bfs(2).nth(1));
+    assert_eq!(0, g.bfs(42).count());
+}
+
+#[test]
+fn walks_depth_first() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    for v in 0..5 {
+        g.add(v);
+    }
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(1));
+    g.bind(1, 3, Label::Alpha(0));
+    g.bind(3, 0, Label::Alpha(0));
+    g.bind(2, 4, Label::Alpha(0));
+    let all: Vec<(usize, usize)> = g.dfs(0).map(|(v, d, _)| (v, d)).collect();
+    assert_eq!(vec![(0, 0), (1, 1), (3, 2), (2, 1), (4, 2)], all);
+    assert_eq!(Some((2, 1, Some(Label::Alpha(1)))), g.dfs(0).nth(3));
+    assert_eq!(0, g.dfs(42).count());
+}
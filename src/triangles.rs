@@ -0,0 +1,106 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use std::collections::{HashMap, HashSet};
+use std::ops::Bound::{Excluded, Unbounded};
+
+impl<const N: usize> Sodg<N> {
+    /// Count directed triangles, which are cycles of three different
+    /// vertices, like `A→B→C→A`.
+    ///
+    /// Each cycle is counted once, no matter how many edges connect
+    /// the same pair of vertices. For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Alpha(0));
+    /// g.bind(1, 2, Label::Alpha(0));
+    /// g.bind(2, 0, Label::Alpha(0));
+    /// assert_eq!(1, g.count_triangles());
+    /// ```
+    #[must_use]
+    pub fn count_triangles(&self) -> usize {
+        let rel = self.to_binary_relation();
+        let mut out: HashMap<usize, HashSet<usize>> = HashMap::new();
+        for (a, b) in &rel {
+            out.entry(*a).or_default().insert(*b);
+        }
+        let mut total = 0;
+        for (a, b) in rel.iter().filter(|(a, b)| b > a) {
+            if let Some(next) = out.get(b) {
+                total += next
+                    .iter()
+                    .filter(|c| *c > a && *c != b && rel.contains(&(**c, *a)))
+                    .count();
+            }
+        }
+        total
+    }
+
+    /// Count undirected triangles, which are sets of three different
+    /// vertices, connected to each other by edges in any direction.
+    #[must_use]
+    pub fn count_undirected_triangles(&self) -> usize {
+        let adj = self.undirected();
+        let mut total = 0;
+        for (a, near) in &adj {
+            for b in near.range((Excluded(a), Unbounded)) {
+                total += adj[b]
+                    .range((Excluded(b), Unbounded))
+                    .filter(|c| near.contains(c))
+                    .count();
+            }
+        }
+        total
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[test]
+fn counts_triangles_in_complete_graphs() {
+    let k3: Sodg<16> = Sodg::complete(3, |i, j| format!("{i}_{j}")).unwrap();
+    assert_eq!(2, k3.count_triangles());
+    assert_eq!(1, k3.count_undirected_triangles());
+    let k4: Sodg<16> = Sodg::complete(4, |i, j| format!("{i}_{j}")).unwrap();
+    assert_eq!(8, k4.count_triangles());
+    assert_eq!(4, k4.count_undirected_triangles());
+    let empty: Sodg<16> = Sodg::empty(256);
+    assert_eq!(0, empty.count_triangles());
+    assert_eq!(0, empty.count_undirected_triangles());
+}
+
+#[test]
+fn counts_directed_cycles() {
+    let mut g: Sodg<16> = Sodg::chain(3, Label::Alpha(0)).unwrap();
+    assert_eq!(0, g.count_triangles());
+    assert_eq!(0, g.count_undirected_triangles());
+    g.bind(0, 2, Label::Alpha(1));
+    assert_eq!(0, g.count_triangles());
+    assert_eq!(1, g.count_undirected_triangles());
+    g.bind(2, 0, Label::Alpha(0));
+    assert_eq!(1, g.count_triangles());
+}
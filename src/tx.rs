@@ -0,0 +1,250 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Hex, Label, Sodg, Tx, TxOp, BRANCH_STATIC, MAX_BRANCH_SIZE};
+use anyhow::{anyhow, Result};
+use std::collections::{HashMap, HashSet};
+
+impl<const N: usize> Sodg<N> {
+    /// Start a transaction, which buffers changes and either applies
+    /// all of them by [`Tx::commit`] or none of them by [`Tx::rollback`].
+    ///
+    /// For example:
+    ///
+    /// ```
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// let mut tx = g.begin();
+    /// tx.add(0);
+    /// tx.bind(0, 1, Label::Alpha(0));
+    /// assert!(tx.commit().is_err());
+    /// assert!(g.is_empty());
+    /// ```
+    pub const fn begin(&mut self) -> Tx<'_, N> {
+        Tx {
+            g: self,
+            ops: vec![],
+        }
+    }
+}
+
+impl<const N: usize> Tx<'_, N> {
+    /// Add a vertex, see [`Sodg::add`].
+    pub fn add(&mut self, v: usize) {
+        self.ops.push(TxOp::Add(v));
+    }
+
+    /// Make an edge, see [`Sodg::bind`].
+    pub fn bind(&mut self, v1: usize, v2: usize, a: Label) {
+        self.ops.push(TxOp::Bind(v1, v2, a));
+    }
+
+    /// Put data into a vertex, see [`Sodg::put`].
+    pub fn put(&mut self, v: usize, d: &Hex) {
+        self.ops.push(TxOp::Put(v, d.clone()));
+    }
+
+    /// Apply all buffered changes to the graph.
+    ///
+    /// # Errors
+    ///
+    /// If any of the changes can't be applied, for example because
+    /// the vertex it refers to is absent, an error will be returned
+    /// and the graph will stay untouched.
+    pub fn commit(self) -> Result<()> {
+        self.validate()?;
+        for op in self.ops {
+            match op {
+                TxOp::Add(v) => self.g.add(v),
+                TxOp::Bind(v1, v2, a) => self.g.bind(v1, v2, a),
+                TxOp::Put(v, d) => self.g.put(v, &d),
+            }
+        }
+        Ok(())
+    }
+
+    /// Forget all buffered changes, leaving the graph untouched.
+    pub fn rollback(self) {}
+
+    /// Make sure all changes can be applied, without applying them.
+    fn validate(&self) -> Result<()> {
+        let cap = self.g.vertices.capacity();
+        let mut added: HashSet<usize> = HashSet::new();
+        let mut labels: HashMap<usize, HashSet<Label>> = HashMap::new();
+        let mut growth = Growth::default();
+        let exists = |v: usize, added: &HashSet<usize>| -> Result<()> {
+            if v >= cap {
+                return Err(anyhow!("The vertex ν{v} doesn't fit into {cap} vertices"));
+            }
+            if !added.contains(&v) && !self.g.alive(v) {
+                return Err(anyhow!("Can't find ν{v}"));
+            }
+            Ok(())
+        };
+        for (pos, op) in self.ops.iter().enumerate() {
+            let checked = match op {
+                TxOp::Add(v) => {
+                    added.insert(*v);
+                    growth.branches.insert(*v, BRANCH_STATIC);
+                    exists(*v, &added)
+                }
+                TxOp::Bind(v1, v2, a) => exists(*v1, &added)
+                    .and_then(|()| exists(*v2, &added))
+                    .and_then(|()| {
+                        let mine = labels
+                            .entry(*v1)
+                            .or_insert_with(|| self.g.kids(*v1).map(|(a, _)| *a).collect());
+                        mine.insert(*a);
+                        if mine.len() > N {
+                            return Err(anyhow!("Too many edges at ν{v1}, {N} is the maximum"));
+                        }
+                        Ok(())
+                    })
+                    .and_then(|()| growth.bind(self.g, *v1, *v2)),
+                TxOp::Put(v, _) => exists(*v, &added),
+            };
+            checked.map_err(|e| e.context(format!("The change no.{pos} can't be applied")))?;
+        }
+        Ok(())
+    }
+}
+
+/// The branches of vertices and the sizes of branches, as they
+/// would be after the changes of a transaction, in order to predict
+/// an overflow before it happens.
+///
+/// This must repeat exactly what [`Sodg::bind`] does with branches.
+#[derive(Default)]
+pub struct Growth {
+    /// Branches of vertices, which are different from the graph.
+    branches: HashMap<usize, usize>,
+    /// Sizes of branches, which are different from the graph.
+    sizes: HashMap<usize, usize>,
+}
+
+impl Growth {
+    /// Get the branch of the vertex.
+    fn branch<const N: usize>(&self, g: &Sodg<N>, v: usize) -> usize {
+        self.branches
+            .get(&v)
+            .copied()
+            .unwrap_or_else(|| g.vertices.get(v).unwrap().branch)
+    }
+
+    /// Get the number of vertices in the branch.
+    fn size<const N: usize>(&self, g: &Sodg<N>, b: usize) -> usize {
+        self.sizes
+            .get(&b)
+            .copied()
+            .unwrap_or_else(|| g.branches.get(b).unwrap().len())
+    }
+
+    /// Put the vertex into the branch, unless the branch is full.
+    fn push<const N: usize>(&mut self, g: &Sodg<N>, b: usize, v: usize) -> Result<()> {
+        let size = self.size(g, b);
+        if size >= MAX_BRANCH_SIZE {
+            return Err(anyhow!(
+                "There is no room for ν{v} in the branch no.{b}, {MAX_BRANCH_SIZE} vertices is the maximum"
+            ));
+        }
+        self.sizes.insert(b, size + 1);
+        self.branches.insert(v, b);
+        Ok(())
+    }
+
+    /// Make an edge from `v1` to `v2`.
+    pub fn bind<const N: usize>(&mut self, g: &Sodg<N>, v1: usize, v2: usize) -> Result<()> {
+        let ours = self.branch(g, v1);
+        let theirs = self.branch(g, v2);
+        if ours == BRANCH_STATIC {
+            if theirs == BRANCH_STATIC {
+                let fresh = g
+                    .branches
+                    .iter()
+                    .map(|(b, _)| b)
+                    .find(|b| self.size(g, *b) == 0)
+                    .unwrap_or(BRANCH_STATIC);
+                if fresh != BRANCH_STATIC {
+                    self.push(g, fresh, v1)?;
+                }
+                self.push(g, fresh, v2)?;
+            } else {
+                self.push(g, theirs, v1)?;
+            }
+        } else if theirs == BRANCH_STATIC {
+            self.push(g, ours, v2)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn commits_all_changes() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    let mut tx = g.begin();
+    tx.add(1);
+    tx.bind(0, 1, Label::Alpha(0));
+    tx.put(1, &Hex::from_str_bytes("hello"));
+    tx.commit().unwrap();
+    assert_eq!(Some(1), g.kid(0, Label::Alpha(0)));
+    assert_eq!("hello", g.peek(1).unwrap().to_utf8().unwrap());
+}
+
+#[test]
+fn rolls_back_changes() {
+    let mut g: Sodg<2> = Sodg::empty(256);
+    g.add(0);
+    let mut tx = g.begin();
+    tx.add(1);
+    tx.rollback();
+    assert_eq!(1, g.len());
+    let mut tx = g.begin();
+    tx.add(1);
+    tx.bind(0, 1, Label::Alpha(0));
+    tx.bind(0, 1, Label::Alpha(1));
+    tx.bind(0, 1, Label::Alpha(2));
+    let err = tx.commit().unwrap_err();
+    assert!(format!("{err:#}").contains("Too many edges at ν0"));
+    assert_eq!(1, g.len());
+    assert_eq!(0, g.kids(0).count());
+}
+
+#[test]
+fn refuses_to_overflow_branch() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    let mut tx = g.begin();
+    for v in 1..17 {
+        tx.add(v);
+        tx.bind(v - 1, v, Label::Alpha(0));
+    }
+    let err = tx.commit().unwrap_err();
+    assert!(format!("{err:#}").contains("no room"));
+    assert_eq!(1, g.len());
+    assert_eq!(0, g.kids(0).count());
+    let mut tx = g.begin();
+    for v in 1..16 {
+        tx.add(v);
+        tx.bind(v - 1, v, Label::Alpha(0));
+    }
+    tx.commit().unwrap();
+    assert_eq!(16, g.len());
+}
@@ -0,0 +1,126 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::Sodg;
+use anyhow::{anyhow, Result};
+use itertools::Itertools;
+use std::collections::HashMap;
+
+impl<const N: usize> Sodg<N> {
+    /// Find all `(vertex, label)` pairs that lead to more than one target.
+    ///
+    /// A vertex may not have two edges with the same label. However, two
+    /// different labels may look the same when printed, for example
+    /// `Label::Greek('ρ')` and `Label::from_str("ρ")`, which is a `Label::Str`.
+    /// Such edges can't be told apart in a script or a dump, that's why
+    /// they are reported as duplicates. The result is sorted:
+    ///
+    /// ```
+    /// use std::str::FromStr;
+    /// use sodg::{Label, Sodg};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// g.add(1);
+    /// g.add(2);
+    /// g.bind(0, 1, Label::Greek('ρ'));
+    /// g.bind(0, 2, Label::from_str("ρ").unwrap());
+    /// assert_eq!(vec![(0, "ρ".to_string())], g.detect_duplicate_edges());
+    /// ```
+    #[must_use]
+    pub fn detect_duplicate_edges(&self) -> Vec<(usize, String)> {
+        let mut dups = vec![];
+        for v in self.keys() {
+            let mut seen: HashMap<String, usize> = HashMap::new();
+            for (a, _) in self.kids(v) {
+                *seen.entry(a.to_string()).or_default() += 1;
+            }
+            for (a, count) in seen {
+                if count > 1 {
+                    dups.push((v, a));
+                }
+            }
+        }
+        dups.sort();
+        dups
+    }
+
+    /// Check all invariants of the graph.
+    ///
+    /// # Errors
+    ///
+    /// If any of the invariants is broken, an error will be returned,
+    /// explaining all problems found.
+    pub fn validate(&self) -> Result<()> {
+        let dups = self.detect_duplicate_edges();
+        if !dups.is_empty() {
+            return Err(anyhow!(
+                "There are {} duplicate edge(s): {}",
+                dups.len(),
+                dups.iter().map(|(v, a)| format!("ν{v}.{a}")).join(", ")
+            ));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+use crate::Label;
+
+#[cfg(test)]
+use std::str::FromStr;
+
+#[test]
+fn finds_no_duplicates_in_fresh_graph() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 1, Label::Alpha(1));
+    assert!(g.detect_duplicate_edges().is_empty());
+    assert!(g.validate().is_ok());
+}
+
+#[test]
+fn finds_look_alike_labels() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    g.bind(0, 1, Label::from_str("ab").unwrap());
+    g.bind(0, 2, Label::Str(['a', ' ', 'b', ' ', ' ', ' ', ' ', ' ']));
+    assert_eq!(vec![(0, "ab".to_string())], g.detect_duplicate_edges());
+    assert!(g.validate().unwrap_err().to_string().contains("ν0.ab"));
+}
+
+#[test]
+fn finds_no_duplicates_after_merge() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.bind(0, 1, Label::from_str("foo").unwrap());
+    let mut extra = Sodg::empty(256);
+    extra.add(0);
+    extra.add(1);
+    extra.add(2);
+    extra.bind(0, 1, Label::from_str("foo").unwrap());
+    extra.bind(0, 2, Label::from_str("bar").unwrap());
+    g.merge(&extra, 0, 0).unwrap();
+    assert!(g.validate().is_ok());
+}
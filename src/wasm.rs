@@ -0,0 +1,221 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::stream::VertexJson;
+use crate::{Hex, Label, Sodg};
+use js_sys::{Array, Uint8Array, JSON};
+use std::str::FromStr;
+use wasm_bindgen::prelude::*;
+
+/// A wrapper of [`Sodg`], which is exported to JavaScript.
+///
+/// Since `wasm-bindgen` doesn't understand generics, the wrapper
+/// always uses `Sodg<16>`. Labels are passed as strings, data as
+/// `Uint8Array`, and errors are thrown as JavaScript exceptions.
+///
+/// This struct is available only with the `wasm-bindgen` feature.
+#[wasm_bindgen]
+pub struct WasmSodg {
+    g: Sodg<16>,
+}
+
+#[wasm_bindgen]
+impl WasmSodg {
+    /// Make an empty graph, see [`Sodg::empty`].
+    #[wasm_bindgen(constructor)]
+    #[must_use]
+    pub fn new(cap: usize) -> Self {
+        Self {
+            g: Sodg::empty(cap),
+        }
+    }
+
+    /// Add a vertex, see [`Sodg::add`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex doesn't fit into the capacity, an error will be thrown.
+    pub fn add(&mut self, v: usize) -> Result<(), JsValue> {
+        self.check(v)?;
+        self.g.add(v);
+        Ok(())
+    }
+
+    /// Bind two vertices, see [`Sodg::bind`].
+    ///
+    /// # Errors
+    ///
+    /// If the label can't be parsed or any of the vertices doesn't fit
+    /// into the capacity, an error will be thrown.
+    pub fn bind(&mut self, v1: usize, v2: usize, a: &str) -> Result<(), JsValue> {
+        self.check(v1)?;
+        self.check(v2)?;
+        self.g.bind(v1, v2, Self::label(a)?);
+        Ok(())
+    }
+
+    /// Find a kid by the label of the edge, see [`Sodg::kid`].
+    ///
+    /// # Errors
+    ///
+    /// If the label can't be parsed or the vertex doesn't fit
+    /// into the capacity, an error will be thrown.
+    pub fn kid(&self, v: usize, a: &str) -> Result<Option<usize>, JsValue> {
+        self.check(v)?;
+        Ok(self.g.kid(v, Self::label(a)?))
+    }
+
+    /// Put data into a vertex, see [`Sodg::put`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex doesn't fit into the capacity, an error will be thrown.
+    pub fn put(&mut self, v: usize, d: &[u8]) -> Result<(), JsValue> {
+        self.check(v)?;
+        self.g.put(v, &Hex::from_slice(d));
+        Ok(())
+    }
+
+    /// Read data from a vertex, see [`Sodg::data`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex doesn't fit into the capacity, an error will be thrown.
+    pub fn data(&mut self, v: usize) -> Result<Option<Uint8Array>, JsValue> {
+        self.check(v)?;
+        Ok(self.g.data(v).map(|d| Uint8Array::from(d.bytes())))
+    }
+
+    /// Get all kids of a vertex, as an array of `[label, vertex]` pairs,
+    /// see [`Sodg::kids`].
+    ///
+    /// # Errors
+    ///
+    /// If the vertex doesn't fit into the capacity, an error will be thrown.
+    pub fn kids(&self, v: usize) -> Result<Array, JsValue> {
+        self.check(v)?;
+        Ok(self
+            .g
+            .kids(v)
+            .map(|(a, to)| Array::of2(&JsValue::from(a.to_string()), &JsValue::from(*to)))
+            .collect())
+    }
+
+    /// Print the entire graph as a JSON string: an array of vertices,
+    /// each with its `id`, `data` (if present), and `edges`, in the same
+    /// format as in [`Sodg::stream_vertices`].
+    ///
+    /// # Errors
+    ///
+    /// If the graph can't be serialized, an error will be thrown.
+    pub fn to_json_string(&self) -> Result<String, JsValue> {
+        self.json().map_err(|e| JsValue::from(e.to_string()))
+    }
+
+    /// Make a graph from a JavaScript object, which has the same
+    /// structure as the JSON printed by [`WasmSodg::to_json_string`].
+    ///
+    /// # Errors
+    ///
+    /// If the object is not a graph or an ID of a vertex doesn't fit
+    /// into the `cap`, an error will be thrown.
+    pub fn from_js_value(val: &JsValue, cap: usize) -> Result<Self, JsValue> {
+        let json: String = JSON::stringify(val)?.into();
+        Self::from_json(&json, cap).map_err(|e| JsValue::from(e.to_string()))
+    }
+}
+
+impl WasmSodg {
+    /// Make sure the vertex fits into the graph, turning an error
+    /// into a JavaScript one.
+    fn check(&self, v: usize) -> Result<(), JsValue> {
+        self.g.fits(v).map_err(|e| JsValue::from(e.to_string()))
+    }
+
+    /// Parse a label, turning an error into a JavaScript one.
+    fn label(a: &str) -> Result<Label, JsValue> {
+        Label::from_str(a).map_err(|e| JsValue::from(e.to_string()))
+    }
+
+    /// Print the graph as JSON.
+    fn json(&self) -> anyhow::Result<String> {
+        let mut keys = self.g.keys();
+        keys.sort_unstable();
+        let vertices: Vec<VertexJson> = keys.into_iter().map(|v| self.g.v_json(v)).collect();
+        Ok(serde_json::to_string(&vertices)?)
+    }
+
+    /// Make a graph from JSON.
+    fn from_json(json: &str, cap: usize) -> anyhow::Result<Self> {
+        let vertices: Vec<VertexJson> = serde_json::from_str(json)?;
+        let mut g = Sodg::empty(cap);
+        for vtx in &vertices {
+            g.v_from_json(vtx)?;
+        }
+        Ok(Self { g })
+    }
+}
+
+#[test]
+fn binds_and_finds_kids() {
+    let mut g = WasmSodg::new(256);
+    g.add(0).unwrap();
+    g.add(1).unwrap();
+    g.bind(0, 1, "foo").unwrap();
+    assert_eq!(Some(1), g.kid(0, "foo").unwrap());
+    assert_eq!(None, g.kid(0, "bar").unwrap());
+}
+
+#[test]
+fn prints_json() {
+    let mut g = WasmSodg::new(256);
+    g.add(0).unwrap();
+    g.put(0, b"hi".as_slice()).unwrap();
+    assert!(g.json().unwrap().starts_with("[{\"id\":0"));
+}
+
+#[test]
+fn reads_what_it_prints() {
+    let mut g = WasmSodg::new(256);
+    g.add(0).unwrap();
+    g.add(1).unwrap();
+    g.bind(0, 1, "foo").unwrap();
+    g.put(1, b"hi".as_slice()).unwrap();
+    let after = WasmSodg::from_json(&g.json().unwrap(), 256).unwrap();
+    assert_eq!(Some(1), after.kid(0, "foo").unwrap());
+    assert_eq!(g.json().unwrap(), after.json().unwrap());
+    assert!(WasmSodg::from_json("{}", 256).is_err());
+}
+
+#[test]
+fn rejects_too_big_vertex() {
+    assert!(WasmSodg::from_json("[{\"id\":100000000,\"edges\":[]}]", 16).is_err());
+    assert!(WasmSodg::from_json("[{\"id\":0,\"edges\":[{\"a\":\"foo\",\"to\":16}]}]", 16).is_err());
+}
+
+#[test]
+fn checks_vertices_against_capacity() {
+    let mut g = WasmSodg::new(16);
+    g.add(15).unwrap();
+    g.put(15, b"hi".as_slice()).unwrap();
+    assert!(g.g.fits(15).is_ok());
+    assert!(g.g.fits(16).is_err());
+    assert!(g.g.fits(usize::MAX).is_err());
+}
@@ -0,0 +1,124 @@
+// Copyright (c) 2022-2025 Objectionary.com
+//
+// Permission is hereby granted, free of charge, to any person obtaining a copy
+// of this software and associated documentation files (the "Software"), to deal
+// in the Software without restriction, including without limitation the rights
+// to use, copy, modify, merge, publish, distribute, sublicense, and/or sell
+// copies of the Software, and to permit persons to whom the Software is
+// furnished to do so, subject to the following conditions:
+//
+// The above copyright notice and this permission notice shall be included
+// in all copies or substantial portions of the Software.
+//
+// THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
+// IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
+// FITNESS FOR A PARTICULAR PURPOSE AND NON-INFRINGEMENT. IN NO EVENT SHALL THE
+// AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
+// LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
+// OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
+// SOFTWARE.
+
+use crate::{Sodg, VertexEvent, VertexEventKind};
+
+impl<const N: usize> Sodg<N> {
+    /// Register a callback, which will be called every time the vertex
+    /// changes: its data is put, an edge is added to it or from it,
+    /// or such an edge is removed.
+    ///
+    /// The callback is called synchronously, right after the change.
+    /// A few callbacks may be attached to the same vertex, they are called
+    /// in the order of registration. For example:
+    ///
+    /// ```
+    /// use std::sync::{Arc, Mutex};
+    /// use sodg::{Hex, Label, Sodg, VertexEventKind};
+    /// let mut g : Sodg<16> = Sodg::empty(256);
+    /// g.add(0);
+    /// let seen = Arc::new(Mutex::new(vec![]));
+    /// let s = seen.clone();
+    /// g.watch_vertex(0, move |e| s.lock().unwrap().push(e.kind));
+    /// g.put(0, &Hex::from(42));
+    /// assert_eq!(vec![VertexEventKind::DataChanged], *seen.lock().unwrap());
+    /// ```
+    pub fn watch_vertex<F: Fn(VertexEvent) + Send + 'static>(&mut self, v: usize, f: F) {
+        self.watchers.entry(v).or_default().push(Box::new(f));
+    }
+
+    /// Tell all callbacks attached to the vertex about a change.
+    pub(crate) fn notify(&self, v: usize, kind: VertexEventKind, vertex: usize) {
+        if self.watchers.is_empty() {
+            return;
+        }
+        if let Some(list) = self.watchers.get(&v) {
+            for f in list {
+                f(VertexEvent { kind, vertex });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+use crate::{Hex, Label};
+
+#[cfg(test)]
+use std::sync::{Arc, Mutex};
+
+#[test]
+fn reports_edges_on_both_ends() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    g.add(2);
+    let outs = Arc::new(Mutex::new(vec![]));
+    let o = outs.clone();
+    g.watch_vertex(0, move |e| o.lock().unwrap().push(e));
+    let ins = Arc::new(Mutex::new(vec![]));
+    let i = ins.clone();
+    g.watch_vertex(1, move |e| i.lock().unwrap().push(e));
+    g.bind(0, 1, Label::Alpha(0));
+    g.bind(0, 2, Label::Alpha(0));
+    assert_eq!(
+        vec![
+            VertexEvent {
+                kind: VertexEventKind::OutgoingEdgeAdded,
+                vertex: 1
+            },
+            VertexEvent {
+                kind: VertexEventKind::OutgoingEdgeRemoved,
+                vertex: 1
+            },
+            VertexEvent {
+                kind: VertexEventKind::OutgoingEdgeAdded,
+                vertex: 2
+            },
+        ],
+        *outs.lock().unwrap()
+    );
+    assert_eq!(
+        vec![
+            VertexEvent {
+                kind: VertexEventKind::IncomingEdgeAdded,
+                vertex: 0
+            },
+            VertexEvent {
+                kind: VertexEventKind::IncomingEdgeRemoved,
+                vertex: 0
+            },
+        ],
+        *ins.lock().unwrap()
+    );
+}
+
+#[test]
+fn ignores_other_vertices() {
+    let mut g: Sodg<16> = Sodg::empty(256);
+    g.add(0);
+    g.add(1);
+    let seen = Arc::new(Mutex::new(0));
+    let s = seen.clone();
+    g.watch_vertex(0, move |_| *s.lock().unwrap() += 1);
+    g.put(1, &Hex::from(1));
+    assert_eq!(0, *seen.lock().unwrap());
+    g.put(0, &Hex::from(1));
+    assert_eq!(1, *seen.lock().unwrap());
+}